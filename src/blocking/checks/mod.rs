@@ -0,0 +1,28 @@
+use crate::blocking::GithubClient;
+
+pub mod runs;
+pub mod suites;
+use runs::Runs;
+use suites::Suites;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::checks::Checks`.
+pub struct Checks<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Checks<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Checks { client }
+    }
+
+    /// Check runs.
+    pub fn runs(&self) -> Runs<'a> {
+        Runs::new(self.client)
+    }
+
+    /// Check suites, which group the check runs of an app on a commit.
+    pub fn suites(&self) -> Suites<'a> {
+        Suites::new(self.client)
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::checks::{NewCheckRun, UpdateCheckRun};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::checks::{Annotation, CheckRun, CheckRunList, RunListParams},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::checks::runs::Runs`.
+pub struct Runs<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Runs<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Runs { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a check run, sending the annotations 50 at a time.
+    pub fn create(&self, owner: &str, repo: &str, run: &NewCheckRun) -> Result<CheckRun, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .checks()
+                .runs()
+                .create(owner, repo, run),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a check run, sending the annotations 50 at a time.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: usize,
+        changes: &UpdateCheckRun,
+    ) -> Result<CheckRun, Error> {
+        self.client
+            .block_on(self.client.as_async().checks().runs().update(
+                owner,
+                repo,
+                check_run_id,
+                changes,
+            ))
+    }
+
+    /// Fetches a check run by its id.
+    pub fn get(&self, owner: &str, repo: &str, check_run_id: usize) -> Result<CheckRun, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .checks()
+                .runs()
+                .get(owner, repo, check_run_id),
+        )
+    }
+
+    /// Fetches a page of the check runs on a commit.
+    pub fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        params: Option<RunListParams>,
+        page: Option<PageParams>,
+    ) -> Result<CheckRunList, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .checks()
+                .runs()
+                .list_for_ref(owner, repo, r#ref, params, page),
+        )
+    }
+
+    /// Fetches every check run on a commit that matches `params`, page by page.
+    pub fn list_for_ref_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        r#ref: &'a str,
+        params: Option<RunListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<CheckRun, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .checks()
+                .runs()
+                .list_for_ref_stream(owner, repo, r#ref, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the check runs in a check suite.
+    pub fn list_for_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: usize,
+        params: Option<RunListParams>,
+        page: Option<PageParams>,
+    ) -> Result<CheckRunList, Error> {
+        self.client
+            .block_on(self.client.as_async().checks().runs().list_for_suite(
+                owner,
+                repo,
+                check_suite_id,
+                params,
+                page,
+            ))
+    }
+
+    /// Fetches a page of the annotations of a check run.
+    pub fn annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Annotation>, Error> {
+        self.client
+            .block_on(self.client.as_async().checks().runs().annotations(
+                owner,
+                repo,
+                check_run_id,
+                page,
+            ))
+    }
+
+    /// Fetches every annotation of a check run, page by page.
+    pub fn annotations_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        check_run_id: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Annotation, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(client.as_async().checks().runs().annotations_stream(
+            owner,
+            repo,
+            check_run_id,
+            page,
+            limits,
+        ));
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Asks for a check run to be run again.
+    pub fn rerequest(&self, owner: &str, repo: &str, check_run_id: usize) -> Result<(), Error> {
+        self.client
+            .block_on(
+                self.client
+                    .as_async()
+                    .checks()
+                    .runs()
+                    .rerequest(owner, repo, check_run_id),
+            )
+    }
+}
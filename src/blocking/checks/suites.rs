@@ -0,0 +1,95 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::checks::{CheckSuite, CheckSuiteList, SuiteListParams},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::checks::suites::Suites`.
+pub struct Suites<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Suites<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Suites { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a check suite for a commit.
+    pub fn create(&self, owner: &str, repo: &str, head_sha: &str) -> Result<CheckSuite, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .checks()
+                .suites()
+                .create(owner, repo, head_sha),
+        )
+    }
+
+    /// Fetches a check suite by its id.
+    pub fn get(&self, owner: &str, repo: &str, check_suite_id: usize) -> Result<CheckSuite, Error> {
+        self.client
+            .block_on(
+                self.client
+                    .as_async()
+                    .checks()
+                    .suites()
+                    .get(owner, repo, check_suite_id),
+            )
+    }
+
+    /// Fetches a page of the check suites on a commit.
+    pub fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        params: Option<SuiteListParams>,
+        page: Option<PageParams>,
+    ) -> Result<CheckSuiteList, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .checks()
+                .suites()
+                .list_for_ref(owner, repo, r#ref, params, page),
+        )
+    }
+
+    /// Fetches every check suite on a commit that matches `params`, page by page.
+    pub fn list_for_ref_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        r#ref: &'a str,
+        params: Option<SuiteListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<CheckSuite, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .checks()
+                .suites()
+                .list_for_ref_stream(owner, repo, r#ref, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Asks for every check run in a check suite to be run again.
+    pub fn rerequest(&self, owner: &str, repo: &str, check_suite_id: usize) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().checks().suites().rerequest(
+                owner,
+                repo,
+                check_suite_id,
+            ))
+    }
+}
@@ -0,0 +1,269 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::{
+    git::{Mode, NewCommit, NewTag, NewTree, ShaRef},
+    GitUser,
+};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::git::{Blob, Commit, Ref, Tag, Tree},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::git::Git`.
+pub struct Git<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Git<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Git { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Starts a commit of changes to many files on `branch`.
+    /// # Errors
+    /// Will error if the message is empty.
+    pub fn commit_builder(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        branch: &'a str,
+        message: impl Into<String>,
+    ) -> Result<CommitBuilder<'a>, Error> {
+        Ok(CommitBuilder {
+            client: self.client,
+            inner: self
+                .client
+                .as_async()
+                .git()
+                .commit_builder(owner, repo, branch, message)?,
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a blob.
+    pub fn create_blob(&self, owner: &str, repo: &str, content: &[u8]) -> Result<ShaRef, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .git()
+                .create_blob(owner, repo, content),
+        )
+    }
+
+    /// Fetches a blob.
+    pub fn get_blob(&self, owner: &str, repo: &str, sha: &str) -> Result<Blob, Error> {
+        self.client
+            .block_on(self.client.as_async().git().get_blob(owner, repo, sha))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a tree.
+    pub fn create_tree(&self, owner: &str, repo: &str, tree: &NewTree) -> Result<Tree, Error> {
+        self.client
+            .block_on(self.client.as_async().git().create_tree(owner, repo, tree))
+    }
+
+    /// Fetches a tree, optionally with the entries of every subtree.
+    pub fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        recursive: bool,
+    ) -> Result<Tree, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .git()
+                .get_tree(owner, repo, tree_sha, recursive),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a commit.
+    pub fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &NewCommit,
+    ) -> Result<Commit, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .git()
+                .create_commit(owner, repo, commit),
+        )
+    }
+
+    /// Fetches a commit by its SHA.
+    pub fn get_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<Commit, Error> {
+        self.client
+            .block_on(self.client.as_async().git().get_commit(owner, repo, sha))
+    }
+
+    /// Fetches a page of the refs that start with `prefix`.
+    pub fn list_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        prefix: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Ref>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .git()
+                .list_refs(owner, repo, prefix, page),
+        )
+    }
+
+    /// Fetches every ref that starts with `prefix`, page by page.
+    pub fn list_refs_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        prefix: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Ref, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .git()
+                .list_refs_stream(owner, repo, prefix, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a ref, like `heads/main`.
+    pub fn get_ref(&self, owner: &str, repo: &str, r#ref: &str) -> Result<Ref, Error> {
+        self.client
+            .block_on(self.client.as_async().git().get_ref(owner, repo, r#ref))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a ref, like `refs/heads/feature`.
+    pub fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        sha: &str,
+    ) -> Result<Ref, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .git()
+                .create_ref(owner, repo, r#ref, sha),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Points a ref to `sha`.
+    pub fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        sha: &str,
+        force: bool,
+    ) -> Result<Ref, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .git()
+                .update_ref(owner, repo, r#ref, sha, force),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a ref.
+    pub fn delete_ref(&self, owner: &str, repo: &str, r#ref: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().git().delete_ref(owner, repo, r#ref))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates an annotated tag object.
+    pub fn create_tag(&self, owner: &str, repo: &str, tag: &NewTag) -> Result<Tag, Error> {
+        self.client
+            .block_on(self.client.as_async().git().create_tag(owner, repo, tag))
+    }
+
+    /// Fetches an annotated tag object by its SHA.
+    pub fn get_tag(&self, owner: &str, repo: &str, sha: &str) -> Result<Tag, Error> {
+        self.client
+            .block_on(self.client.as_async().git().get_tag(owner, repo, sha))
+    }
+}
+
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+#[derive(Clone)]
+/// The blocking version of `use_github_api::git::CommitBuilder`.
+pub struct CommitBuilder<'a> {
+    client: &'a GithubClient,
+    inner: crate::git::CommitBuilder<'a>,
+}
+
+#[cfg(any(feature = "auth", doc))]
+impl<'a> CommitBuilder<'a> {
+    /// Creates or replaces a file, with text or bytes.
+    pub fn write(mut self, path: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+        self.inner = self.inner.write(path, content);
+        self
+    }
+
+    /// Creates or replaces a file with a mode.
+    pub fn write_with_mode(
+        mut self,
+        path: impl Into<String>,
+        content: impl Into<Vec<u8>>,
+        mode: Mode,
+    ) -> Self {
+        self.inner = self.inner.write_with_mode(path, content, mode);
+        self
+    }
+
+    /// Deletes a file.
+    pub fn delete(mut self, path: impl Into<String>) -> Self {
+        self.inner = self.inner.delete(path);
+        self
+    }
+
+    /// Sets the author.
+    pub fn author(mut self, author: GitUser) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the committer.
+    pub fn committer(mut self, committer: GitUser) -> Self {
+        self.inner = self.inner.committer(committer);
+        self
+    }
+
+    /// Sets how many times the commit is made again when the branch moves on. Defaults to 3.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.inner = self.inner.retries(retries);
+        self
+    }
+
+    /// Makes the commit, and moves the branch to it.
+    pub fn commit(self) -> Result<Commit, Error> {
+        self.client.block_on(self.inner.commit())
+    }
+}
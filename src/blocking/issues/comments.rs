@@ -0,0 +1,158 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::issues::Comment,
+    Error,
+};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::issues::comments::Comments`.
+pub struct Comments<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Comments<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Comments { client }
+    }
+
+    /// Fetches a page of the comments on an issue, oldest first.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Comment>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .comments()
+                .list(owner, repo, number, since, page),
+        )
+    }
+
+    /// Fetches every comment on an issue, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Comment, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .comments()
+                .list_stream(owner, repo, number, since, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the comments on every issue in a repository, oldest first.
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Comment>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .comments()
+                .list_for_repo(owner, repo, since, page),
+        )
+    }
+
+    /// Fetches every comment on every issue in a repository, page by page.
+    pub fn list_for_repo_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Comment, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .comments()
+                .list_for_repo_stream(owner, repo, since, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a comment by its id.
+    pub fn get(&self, owner: &str, repo: &str, comment_id: usize) -> Result<Comment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .comments()
+                .get(owner, repo, comment_id),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Comments on an issue.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        body: &str,
+    ) -> Result<Comment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .comments()
+                .create(owner, repo, number, body),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces the body of a comment.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: usize,
+        body: &str,
+    ) -> Result<Comment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .comments()
+                .update(owner, repo, comment_id, body),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a comment.
+    pub fn delete(&self, owner: &str, repo: &str, comment_id: usize) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .comments()
+                .delete(owner, repo, comment_id),
+        )
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::issues::{NewLabel, UpdateLabel};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::issues::Label,
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::issues::labels::Labels`.
+pub struct Labels<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Labels<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Labels { client }
+    }
+
+    /// Fetches a page of the labels of a repository.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Label>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .list(owner, repo, page),
+        )
+    }
+
+    /// Fetches every label of a repository, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Label, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .labels()
+                .list_stream(owner, repo, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a label by its name.
+    pub fn get(&self, owner: &str, repo: &str, name: &str) -> Result<Label, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .get(owner, repo, name),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a label.
+    pub fn create(&self, owner: &str, repo: &str, label: &NewLabel) -> Result<Label, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .create(owner, repo, label),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a label, and returns the updated label.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        changes: &UpdateLabel,
+    ) -> Result<Label, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .update(owner, repo, name, changes),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a label. It is also removed from every issue that has it.
+    pub fn delete(&self, owner: &str, repo: &str, name: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .delete(owner, repo, name),
+        )
+    }
+
+    /// Fetches a page of the labels on an issue.
+    pub fn list_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Label>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .list_for_issue(owner, repo, number, page),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds labels to an issue, and returns every label that is on it now.
+    pub fn add_to_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        names: &[&str],
+    ) -> Result<Vec<Label>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .add_to_issue(owner, repo, number, names),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces all of the labels on an issue.
+    pub fn set_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        names: &[&str],
+    ) -> Result<Vec<Label>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .set_for_issue(owner, repo, number, names),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a label from an issue, and returns the labels that are left.
+    pub fn remove_from_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        name: &str,
+    ) -> Result<Vec<Label>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .remove_from_issue(owner, repo, number, name),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes every label from an issue.
+    pub fn remove_all_from_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+    ) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .labels()
+                .remove_all_from_issue(owner, repo, number),
+        )
+    }
+}
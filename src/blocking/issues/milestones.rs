@@ -0,0 +1,119 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::issues::{NewMilestone, UpdateMilestone};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::issues::{Milestone, MilestoneListParams},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::issues::milestones::Milestones`.
+pub struct Milestones<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Milestones<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Milestones { client }
+    }
+
+    /// Fetches a page of the milestones of a repository.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: Option<MilestoneListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Milestone>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .milestones()
+                .list(owner, repo, params, page),
+        )
+    }
+
+    /// Fetches every milestone of a repository that matches `params`, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        params: Option<MilestoneListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Milestone, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .milestones()
+                .list_stream(owner, repo, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a milestone by its number.
+    pub fn get(&self, owner: &str, repo: &str, number: usize) -> Result<Milestone, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .milestones()
+                .get(owner, repo, number),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a milestone.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &NewMilestone,
+    ) -> Result<Milestone, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .milestones()
+                .create(owner, repo, milestone),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a milestone, and returns the updated milestone.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        changes: &UpdateMilestone,
+    ) -> Result<Milestone, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .milestones()
+                .update(owner, repo, number, changes),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a milestone. Its issues are kept, but lose the milestone.
+    pub fn delete(&self, owner: &str, repo: &str, number: usize) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .milestones()
+                .delete(owner, repo, number),
+        )
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::issues::{LockReason, NewIssue, UpdateIssue};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::issues::{Issue, ListParams},
+    Error,
+};
+use futures::StreamExt;
+
+pub mod comments;
+pub mod labels;
+pub mod milestones;
+use comments::Comments;
+use labels::Labels;
+use milestones::Milestones;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::issues::Issues`.
+pub struct Issues<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Issues<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Issues { client }
+    }
+
+    /// Comments on issues and pull requests.
+    pub fn comments(&self) -> Comments<'a> {
+        Comments::new(self.client)
+    }
+
+    /// Labels of repositories and issues.
+    pub fn labels(&self) -> Labels<'a> {
+        Labels::new(self.client)
+    }
+
+    /// Milestones of repositories.
+    pub fn milestones(&self) -> Milestones<'a> {
+        Milestones::new(self.client)
+    }
+
+    /// Fetches a page of the issues (and pull requests) in a repository.
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Issue>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .list_for_repo(owner, repo, params, page),
+        )
+    }
+
+    /// Fetches every issue (and pull request) in a repository that matches `params`, page by page.
+    pub fn list_for_repo_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Issue, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .list_for_repo_stream(owner, repo, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the issues in an organization that the current authenticated user is involved with.
+    pub fn list_for_org(
+        &self,
+        org: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Issue>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .list_for_org(org, params, page),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every issue in an organization that matches `params`, page by page.
+    pub fn list_for_org_iter(
+        &self,
+        org: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Issue, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .list_for_org_stream(org, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the issues of the current authenticated user.
+    pub fn list_for_user(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Issue>, Error> {
+        self.client
+            .block_on(self.client.as_async().issues().list_for_user(params, page))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every issue of the current authenticated user that matches `params`, page by page.
+    pub fn list_for_user_iter(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Issue, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .issues()
+                .list_for_user_stream(params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches an issue (or pull request) by its number.
+    pub fn get(&self, owner: &str, repo: &str, number: usize) -> Result<Issue, Error> {
+        self.client
+            .block_on(self.client.as_async().issues().get(owner, repo, number))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates an issue.
+    pub fn create(&self, owner: &str, repo: &str, issue: &NewIssue) -> Result<Issue, Error> {
+        self.client
+            .block_on(self.client.as_async().issues().create(owner, repo, issue))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes an issue, and returns the updated issue.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        changes: &UpdateIssue,
+    ) -> Result<Issue, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .update(owner, repo, number, changes),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Locks the conversation of an issue, so that only collaborators can comment.
+    pub fn lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        reason: Option<LockReason>,
+    ) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .issues()
+                .lock(owner, repo, number, reason),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Unlocks the conversation of an issue.
+    pub fn unlock(&self, owner: &str, repo: &str, number: usize) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().issues().unlock(owner, repo, number))
+    }
+}
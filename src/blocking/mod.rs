@@ -0,0 +1,163 @@
+//! A synchronous client, for code that does not run an async runtime (like CLI tools and build scripts).
+//! It wraps the async `GithubClient` and drives it on a runtime of its own, so it sends the same requests and gives the same schema types.
+//! Do not use it from inside an async runtime, since blocking on one from within another panics.
+use crate::{schema::rate_limit::RateLimit, transport::Transport, Error};
+use std::{cell::RefCell, future::Future, sync::Arc};
+use tokio::runtime::{Builder, Runtime};
+
+pub mod checks;
+pub mod git;
+pub mod issues;
+pub mod orgs;
+pub mod pulls;
+mod rate_limit;
+pub mod repos;
+pub mod teams;
+pub mod users;
+
+pub use checks::Checks;
+pub use git::Git;
+pub use issues::Issues;
+pub use orgs::Orgs;
+pub use pulls::Pulls;
+pub use rate_limit::RateLimits;
+pub use repos::Repos;
+pub use teams::Teams;
+pub use users::Users;
+
+thread_local! {
+    /// The rate limit of the latest response that was waited for on this thread.
+    static THREAD_RATE_LIMIT: RefCell<Option<RateLimit>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone)]
+/// The blocking version of `use_github_api::GithubClient`. Every endpoint has the same name, but blocks until it is done instead of returning a future.
+/// Cloning it is cheap, and clones share the runtime, the connection pool, and the rate limit info.
+/// # Examples
+/// ```rust,no_run
+/// # #[cfg(feature = "auth")]
+/// # #[cfg(not(feature = "enterprise"))]
+/// # {
+/// use use_github_api::blocking::GithubClient;
+/// let client = GithubClient::new("ghp_akjsdh").unwrap(); // DO NOT ACTUALLY HARDCODE TOKENS IN YOUR APP!!!
+/// let user = client.users().user("octocat").unwrap();
+/// # }
+/// ```
+pub struct GithubClient {
+    inner: crate::GithubClient,
+    runtime: Arc<Runtime>,
+}
+
+impl GithubClient {
+    /// Creates a new blocking `GithubClient`. The arguments are the same as for `use_github_api::GithubClient::new`.
+    /// # Errors
+    /// Will error if the client could not be created, or if the runtime could not be started.
+    pub fn new(
+        #[cfg(feature = "enterprise")] base_url: &str,
+        #[cfg(feature = "auth")] auth_token: &str,
+    ) -> Result<GithubClient, Error> {
+        Self::from_async(crate::GithubClient::new(
+            #[cfg(feature = "enterprise")]
+            base_url,
+            #[cfg(feature = "auth")]
+            auth_token,
+        )?)
+    }
+
+    /// Wraps an async `GithubClient`, like one made with `GithubClientBuilder`.
+    /// # Errors
+    /// Will error if the runtime could not be started.
+    pub fn from_async(client: crate::GithubClient) -> Result<GithubClient, Error> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| Error::Transport(Box::new(err)))?;
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Replaces the `Transport` that requests are sent through. The default is a `ReqwestTransport`.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.inner = self.inner.with_transport(transport);
+        self
+    }
+
+    /// The async client that this one wraps.
+    pub fn as_async(&self) -> &crate::GithubClient {
+        &self.inner
+    }
+
+    pub fn users(&self) -> Users<'_> {
+        Users::new(self)
+    }
+
+    pub fn rate_limit(&self) -> RateLimits<'_> {
+        RateLimits::new(self)
+    }
+
+    pub fn repos(&self) -> Repos<'_> {
+        Repos::new(self)
+    }
+
+    pub fn issues(&self) -> Issues<'_> {
+        Issues::new(self)
+    }
+
+    pub fn pulls(&self) -> Pulls<'_> {
+        Pulls::new(self)
+    }
+
+    pub fn checks(&self) -> Checks<'_> {
+        Checks::new(self)
+    }
+
+    pub fn git(&self) -> Git<'_> {
+        Git::new(self)
+    }
+
+    pub fn orgs(&self) -> Orgs<'_> {
+        Orgs::new(self)
+    }
+
+    pub fn teams(&self) -> Teams<'_> {
+        Teams::new(self)
+    }
+
+    /// The rate limit info from the `X-RateLimit-*` headers of the latest response, if there has been one.
+    /// Clones share this value, so with clones on other threads, use `with_rate_limit` to know which response it came from.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.inner.last_rate_limit()
+    }
+
+    /// Calls `f` (usually a call to an endpoint), and gives back what it returns along with the rate limit info of the latest response it got.
+    /// Requests on other threads do not affect it, even on clones of the client.
+    /// # Examples
+    /// ```rust,ignore
+    /// let (user, rate_limit) = client.with_rate_limit(|| client.users().user("octocat"));
+    /// ```
+    pub fn with_rate_limit<T>(&self, f: impl FnOnce() -> T) -> (T, Option<RateLimit>) {
+        THREAD_RATE_LIMIT.with(|rate_limit| rate_limit.borrow_mut().take());
+        let output = f();
+        (
+            output,
+            THREAD_RATE_LIMIT.with(|rate_limit| rate_limit.borrow_mut().take()),
+        )
+    }
+
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let (output, rate_limit) = self.runtime.block_on(self.inner.with_rate_limit(future));
+        if rate_limit.is_some() {
+            THREAD_RATE_LIMIT.with(|thread| *thread.borrow_mut() = rate_limit);
+        }
+        output
+    }
+}
+
+#[cfg(not(feature = "auth"))]
+impl Default for GithubClient {
+    fn default() -> Self {
+        Self::new().expect("Error while creating default client")
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::users::list,
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::orgs::blocks::Blocks`.
+pub struct Blocks<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Blocks<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Blocks { client }
+    }
+
+    /// Fetches a page of the users that an organization has blocked.
+    pub fn list(&self, org: &str, params: Option<PageParams>) -> Result<Vec<list::User>, Error> {
+        self.client
+            .block_on(self.client.as_async().orgs().blocks().list(org, params))
+    }
+
+    /// Fetches every user that an organization has blocked, page by page.
+    pub fn list_iter(
+        &self,
+        org: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .orgs()
+                .blocks()
+                .list_stream(org, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if an organization has blocked a user.
+    pub fn is_blocked(&self, org: &str, username: &str) -> Result<bool, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .blocks()
+                .is_blocked(org, username),
+        )
+    }
+
+    /// Blocks a user from an organization.
+    pub fn block(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().orgs().blocks().block(org, username))
+    }
+
+    /// Unblocks a user from an organization.
+    pub fn unblock(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .blocks()
+                .unblock(org, username),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::orgs::invitations::{Invitation, NewInvitation},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::orgs::invitations::Invitations`.
+pub struct Invitations<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Invitations<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Invitations { client }
+    }
+
+    /// Fetches a page of the pending invitations of an organization.
+    pub fn list(&self, org: &str, params: Option<PageParams>) -> Result<Vec<Invitation>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .invitations()
+                .list(org, params),
+        )
+    }
+
+    /// Fetches every pending invitation of an organization, page by page.
+    pub fn list_iter(
+        &self,
+        org: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Invitation, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .orgs()
+                .invitations()
+                .list_stream(org, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Invites a user, or an email address, to an organization.
+    pub fn create(&self, org: &str, invitation: &NewInvitation) -> Result<Invitation, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .invitations()
+                .create(org, invitation),
+        )
+    }
+
+    /// Cancels an invitation.
+    pub fn cancel(&self, org: &str, invitation_id: usize) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .invitations()
+                .cancel(org, invitation_id),
+        )
+    }
+}
@@ -0,0 +1,188 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::orgs::members::{Membership, NewRole};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::{
+        orgs::members::{Filter, ListParams},
+        users::list::User,
+    },
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::orgs::members::Members`.
+pub struct Members<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Members<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Members { client }
+    }
+
+    /// Fetches a page of the members of an organization.
+    pub fn list(
+        &self,
+        org: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<User>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .list(org, params, page),
+        )
+    }
+
+    /// Fetches every member of an organization that matches `params`, page by page.
+    pub fn list_iter(
+        &self,
+        org: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .orgs()
+                .members()
+                .list_stream(org, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if a user is a member of an organization.
+    pub fn is_member(&self, org: &str, username: &str) -> Result<bool, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .is_member(org, username),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a user from an organization.
+    pub fn remove(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .remove(org, username),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches the membership of a user in an organization.
+    pub fn membership(&self, org: &str, username: &str) -> Result<Membership, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .membership(org, username),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Sets the role of a member, or invites a user with a role.
+    pub fn set_membership(
+        &self,
+        org: &str,
+        username: &str,
+        role: NewRole,
+    ) -> Result<Membership, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .set_membership(org, username, role),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a member, or cancels the invitation of a user.
+    pub fn remove_membership(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .remove_membership(org, username),
+        )
+    }
+
+    /// Fetches a page of the outside collaborators of an organization.
+    pub fn outside_collaborators(
+        &self,
+        org: &str,
+        filter: Option<Filter>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<User>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .outside_collaborators(org, filter, page),
+        )
+    }
+
+    /// Fetches every outside collaborator of an organization, page by page.
+    pub fn outside_collaborators_iter(
+        &self,
+        org: &'a str,
+        filter: Option<Filter>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .orgs()
+                .members()
+                .outside_collaborators_stream(org, filter, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Turns a member into an outside collaborator.
+    pub fn convert_to_outside_collaborator(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .convert_to_outside_collaborator(org, username),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes an outside collaborator from every repository of an organization.
+    pub fn remove_outside_collaborator(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .members()
+                .remove_outside_collaborator(org, username),
+        )
+    }
+}
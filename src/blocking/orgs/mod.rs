@@ -0,0 +1,128 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::orgs::{list, single},
+    Error,
+};
+use futures::StreamExt;
+
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod blocks;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod invitations;
+pub mod members;
+#[cfg(any(feature = "auth", doc))]
+use blocks::Blocks;
+#[cfg(any(feature = "auth", doc))]
+use invitations::Invitations;
+use members::Members;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::orgs::Orgs`.
+pub struct Orgs<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Orgs<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Orgs { client }
+    }
+
+    /// Members and outside collaborators of organizations.
+    pub fn members(&self) -> Members<'a> {
+        Members::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Invitations to join organizations.
+    pub fn invitations(&self) -> Invitations<'a> {
+        Invitations::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The users blocked by organizations.
+    pub fn blocks(&self) -> Blocks<'a> {
+        Blocks::new(self.client)
+    }
+
+    /// Fetches an organization.
+    pub fn get(&self, org: &str) -> Result<single::Organization, Error> {
+        self.client.block_on(self.client.as_async().orgs().get(org))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Updates an organization, and returns the updated organization.
+    pub fn update(
+        &self,
+        org: &str,
+        params: single::UpdateParams,
+    ) -> Result<single::Organization, Error> {
+        self.client
+            .block_on(self.client.as_async().orgs().update(org, params))
+    }
+
+    /// Fetches a page of the organizations that a user is a public member of.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::Organization>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .orgs()
+                .list_for_user(username, params),
+        )
+    }
+
+    /// Fetches every organization that a user is a public member of, page by page.
+    pub fn list_for_user_iter(
+        &self,
+        username: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::Organization, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .orgs()
+                .list_for_user_stream(username, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the organizations that the current authenticated user is a member of.
+    pub fn list_for_current(
+        &self,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::Organization>, Error> {
+        self.client
+            .block_on(self.client.as_async().orgs().list_for_current(params))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every organization that the current authenticated user is a member of, page by page.
+    pub fn list_for_current_iter(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::Organization, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .orgs()
+                .list_for_current_stream(params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+}
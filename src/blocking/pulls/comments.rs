@@ -0,0 +1,175 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::pulls::NewReviewComment;
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::pulls::ReviewComment,
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::pulls::comments::Comments`.
+pub struct Comments<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Comments<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Comments { client }
+    }
+
+    /// Fetches a page of the review comments on a pull request, oldest first.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<ReviewComment>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .list(owner, repo, number, page),
+        )
+    }
+
+    /// Fetches every review comment on a pull request, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<ReviewComment, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .pulls()
+                .comments()
+                .list_stream(owner, repo, number, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the review comments on every pull request in a repository.
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<ReviewComment>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .list_for_repo(owner, repo, page),
+        )
+    }
+
+    /// Fetches every review comment on every pull request in a repository, page by page.
+    pub fn list_for_repo_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<ReviewComment, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .pulls()
+                .comments()
+                .list_for_repo_stream(owner, repo, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a review comment by its id.
+    pub fn get(&self, owner: &str, repo: &str, comment_id: usize) -> Result<ReviewComment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .get(owner, repo, comment_id),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Comments on a line of the diff, outside of a review.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        comment: &NewReviewComment,
+    ) -> Result<ReviewComment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .create(owner, repo, number, comment),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replies to a review comment.
+    pub fn reply(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        comment_id: usize,
+        body: &str,
+    ) -> Result<ReviewComment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .reply(owner, repo, number, comment_id, body),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces the body of a review comment.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: usize,
+        body: &str,
+    ) -> Result<ReviewComment, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .update(owner, repo, comment_id, body),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a review comment.
+    pub fn delete(&self, owner: &str, repo: &str, comment_id: usize) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .comments()
+                .delete(owner, repo, comment_id),
+        )
+    }
+}
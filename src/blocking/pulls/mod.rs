@@ -0,0 +1,243 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::pulls::{MergeParams, MergeResult, NewPull, UpdatePull};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::pulls::{Commit, File, ListParams, PullRequest},
+    Error,
+};
+use futures::StreamExt;
+
+pub mod comments;
+pub mod reviews;
+use comments::Comments;
+use reviews::Reviews;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::pulls::Pulls`.
+pub struct Pulls<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Pulls<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Pulls { client }
+    }
+
+    /// Reviews of pull requests.
+    pub fn reviews(&self) -> Reviews<'a> {
+        Reviews::new(self.client)
+    }
+
+    /// Comments on lines of the diffs of pull requests.
+    pub fn comments(&self) -> Comments<'a> {
+        Comments::new(self.client)
+    }
+
+    /// Fetches a page of the pull requests in a repository.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<PullRequest>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .list(owner, repo, params, page),
+        )
+    }
+
+    /// Fetches every pull request in a repository that matches `params`, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<PullRequest, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .pulls()
+                .list_stream(owner, repo, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a pull request by its number, with whether it can be merged.
+    pub fn get(&self, owner: &str, repo: &str, number: usize) -> Result<PullRequest, Error> {
+        self.client
+            .block_on(self.client.as_async().pulls().get(owner, repo, number))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a pull request.
+    pub fn create(&self, owner: &str, repo: &str, pull: &NewPull) -> Result<PullRequest, Error> {
+        self.client
+            .block_on(self.client.as_async().pulls().create(owner, repo, pull))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a pull request, and returns the updated pull request.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        changes: &UpdatePull,
+    ) -> Result<PullRequest, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .update(owner, repo, number, changes),
+        )
+    }
+
+    /// Fetches a page of the commits on a pull request, oldest first.
+    pub fn commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Commit>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .commits(owner, repo, number, page),
+        )
+    }
+
+    /// Fetches every commit on a pull request, page by page.
+    pub fn commits_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Commit, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .pulls()
+                .commits_stream(owner, repo, number, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the files changed by a pull request, with their patches.
+    pub fn files(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<File>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .files(owner, repo, number, page),
+        )
+    }
+
+    /// Fetches every file changed by a pull request, page by page.
+    pub fn files_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<File, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .pulls()
+                .files_stream(owner, repo, number, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if a pull request has been merged.
+    pub fn is_merged(&self, owner: &str, repo: &str, number: usize) -> Result<bool, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .is_merged(owner, repo, number),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Merges a pull request.
+    pub fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        params: &MergeParams,
+    ) -> Result<MergeResult, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .merge(owner, repo, number, params),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Requests reviews from users and teams, and returns the updated pull request.
+    pub fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        reviewers: &[&str],
+        team_reviewers: &[&str],
+    ) -> Result<PullRequest, Error> {
+        self.client
+            .block_on(self.client.as_async().pulls().request_reviewers(
+                owner,
+                repo,
+                number,
+                reviewers,
+                team_reviewers,
+            ))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Takes back review requests from users and teams, and returns the updated pull request.
+    pub fn remove_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        reviewers: &[&str],
+        team_reviewers: &[&str],
+    ) -> Result<PullRequest, Error> {
+        self.client
+            .block_on(self.client.as_async().pulls().remove_requested_reviewers(
+                owner,
+                repo,
+                number,
+                reviewers,
+                team_reviewers,
+            ))
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::pulls::{NewReview, ReviewEvent};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::pulls::{Review, ReviewComment},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::pulls::reviews::Reviews`.
+pub struct Reviews<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Reviews<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Reviews { client }
+    }
+
+    /// Fetches a page of the reviews of a pull request, oldest first.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Review>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .list(owner, repo, number, page),
+        )
+    }
+
+    /// Fetches every review of a pull request, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Review, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .pulls()
+                .reviews()
+                .list_stream(owner, repo, number, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a review by its id.
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+    ) -> Result<Review, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .get(owner, repo, number, review_id),
+        )
+    }
+
+    /// Fetches a page of the comments that are part of a review.
+    pub fn comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<ReviewComment>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .comments(owner, repo, number, review_id, page),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a review. Without `review.event`, it stays pending until `submit` is called.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review: &NewReview,
+    ) -> Result<Review, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .create(owner, repo, number, review),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces the body of a review.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        body: &str,
+    ) -> Result<Review, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .update(owner, repo, number, review_id, body),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Submits a pending review.
+    pub fn submit(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        event: ReviewEvent,
+        body: Option<&str>,
+    ) -> Result<Review, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .submit(owner, repo, number, review_id, event, body),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a pending review, and returns it.
+    pub fn delete_pending(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+    ) -> Result<Review, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .delete_pending(owner, repo, number, review_id),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Dismisses a submitted review, with a message on why.
+    pub fn dismiss(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        message: &str,
+    ) -> Result<Review, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .pulls()
+                .reviews()
+                .dismiss(owner, repo, number, review_id, message),
+        )
+    }
+}
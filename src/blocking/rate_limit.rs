@@ -0,0 +1,20 @@
+use crate::{blocking::GithubClient, schema::rate_limit::Overview, Error};
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::rate_limit::RateLimits`.
+pub struct RateLimits<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> RateLimits<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        RateLimits { client }
+    }
+
+    /// Fetches the rate limit status for every resource.
+    /// This does not count against the rate limit.
+    pub fn get(&self) -> Result<Overview, Error> {
+        self.client
+            .block_on(self.client.as_async().rate_limit().get())
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::{invitations::Invitation, NewPermission};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::repos::collaborators::{Affiliation, Collaborator, PermissionLevel},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::repos::collaborators::Collaborators`.
+pub struct Collaborators<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Collaborators<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Collaborators { client }
+    }
+
+    /// Fetches a page of the collaborators of a repository.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: Option<Affiliation>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Collaborator>, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().collaborators().list(
+                owner,
+                repo,
+                affiliation,
+                page,
+            ))
+    }
+
+    /// Fetches every collaborator of a repository, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        affiliation: Option<Affiliation>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Collaborator, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(client.as_async().repos().collaborators().list_stream(
+            owner,
+            repo,
+            affiliation,
+            page,
+            limits,
+        ));
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if a user is a collaborator of a repository.
+    pub fn is_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<bool, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .collaborators()
+                .is_collaborator(owner, repo, username),
+        )
+    }
+
+    /// Fetches the access that a user has to a repository.
+    pub fn permission(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<PermissionLevel, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .collaborators()
+                .permission(owner, repo, username),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds a collaborator to a repository, or changes their permission. Returns the invitation, if one was needed.
+    pub fn add(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        permission: NewPermission,
+    ) -> Result<Option<Invitation>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .collaborators()
+                .add(owner, repo, username, permission),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a collaborator from a repository.
+    pub fn remove(&self, owner: &str, repo: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .collaborators()
+                .remove(owner, repo, username),
+        )
+    }
+}
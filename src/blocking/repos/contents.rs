@@ -0,0 +1,107 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::contents::{FileCommit, FileDelete, FileUpdate};
+use crate::{
+    blocking::GithubClient,
+    schema::repos::contents::{ArchiveFormat, Content, File},
+    Error,
+};
+use bytes::Bytes;
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::repos::contents::Contents`.
+pub struct Contents<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Contents<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Contents { client }
+    }
+
+    /// Fetches what is at `path`: a file with its decoded content, or the entries of a directory.
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        r#ref: Option<&str>,
+    ) -> Result<Content, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .contents()
+                .get(owner, repo, path, r#ref),
+        )
+    }
+
+    /// Fetches the README of a repository, with its decoded content.
+    pub fn readme(&self, owner: &str, repo: &str, r#ref: Option<&str>) -> Result<File, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .contents()
+                .readme(owner, repo, r#ref),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a file, or updates it if `file.sha` is set, in a new commit.
+    pub fn put(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        file: &FileUpdate,
+    ) -> Result<FileCommit, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .contents()
+                .put(owner, repo, path, file),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a file in a new commit.
+    pub fn delete(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        file: &FileDelete,
+    ) -> Result<FileCommit, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .contents()
+                .delete(owner, repo, path, file),
+        )
+    }
+
+    /// Downloads a tarball or zipball of the repository at `r#ref`, or at the default branch.
+    /// The archive comes in chunks, which are only downloaded once the iterator gets to them. If the request fails, the error is the only item.
+    pub fn archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        format: ArchiveFormat,
+        r#ref: Option<&str>,
+    ) -> impl Iterator<Item = Result<Bytes, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .repos()
+                .contents()
+                .archive(owner, repo, format, r#ref),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+}
@@ -0,0 +1,270 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::{NewRepo, UpdateRepo};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::repos::{Contributor, Languages, ListParams, Repository, Tag},
+    Error,
+};
+use futures::StreamExt;
+
+pub mod collaborators;
+pub mod contents;
+pub mod statuses;
+use collaborators::Collaborators;
+use contents::Contents;
+use statuses::Statuses;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::repos::Repos`.
+pub struct Repos<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Repos<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Repos { client }
+    }
+
+    /// Collaborators of repositories, and the access that users have to them.
+    pub fn collaborators(&self) -> Collaborators<'a> {
+        Collaborators::new(self.client)
+    }
+
+    /// Files and directories in repositories.
+    pub fn contents(&self) -> Contents<'a> {
+        Contents::new(self.client)
+    }
+
+    /// Commit statuses.
+    pub fn statuses(&self) -> Statuses<'a> {
+        Statuses::new(self.client)
+    }
+
+    /// Fetches a repository.
+    pub fn get(&self, owner: &str, repo: &str) -> Result<Repository, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().get(owner, repo))
+    }
+
+    /// Fetches a page of the public repositories of a user.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .list_for_user(username, params, page),
+        )
+    }
+
+    /// Fetches every public repository of a user, page by page.
+    pub fn list_for_user_iter(
+        &self,
+        username: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Repository, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .repos()
+                .list_for_user_stream(username, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the repositories of an organization.
+    pub fn list_for_org(
+        &self,
+        org: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .list_for_org(org, params, page),
+        )
+    }
+
+    /// Fetches every repository of an organization, page by page.
+    pub fn list_for_org_iter(
+        &self,
+        org: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Repository, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .repos()
+                .list_for_org_stream(org, params, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the repositories that the current authenticated user can access, including private ones.
+    pub fn list(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().list(params, page))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every repository that the current authenticated user can access, page by page.
+    pub fn list_iter(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Repository, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(client.as_async().repos().list_stream(params, page, limits));
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a repository for the current authenticated user.
+    pub fn create(&self, repo: &NewRepo) -> Result<Repository, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().create(repo))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a repository in an organization.
+    pub fn create_for_org(&self, org: &str, repo: &NewRepo) -> Result<Repository, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().create_for_org(org, repo))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes the settings of a repository, and returns the updated repository.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        changes: &UpdateRepo,
+    ) -> Result<Repository, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().update(owner, repo, changes))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a repository. Needs the `delete_repo` scope.
+    pub fn delete(&self, owner: &str, repo: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().repos().delete(owner, repo))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Starts transferring a repository to another user or organization.
+    pub fn transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+        new_owner: &str,
+        team_ids: Option<&[usize]>,
+    ) -> Result<Repository, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .transfer(owner, repo, new_owner, team_ids),
+        )
+    }
+
+    /// Fetches the languages of a repository, with how many bytes of code are in each.
+    pub fn languages(&self, owner: &str, repo: &str) -> Result<Languages, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().languages(owner, repo))
+    }
+
+    /// Fetches the topics of a repository.
+    pub fn topics(&self, owner: &str, repo: &str) -> Result<Vec<String>, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().topics(owner, repo))
+    }
+
+    /// Fetches a page of the contributors of a repository.
+    pub fn contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Contributor>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .contributors(owner, repo, page),
+        )
+    }
+
+    /// Fetches every contributor of a repository, page by page.
+    pub fn contributors_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Contributor, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .repos()
+                .contributors_stream(owner, repo, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the tags of a repository.
+    pub fn tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Tag>, Error> {
+        self.client
+            .block_on(self.client.as_async().repos().tags(owner, repo, page))
+    }
+
+    /// Fetches every tag of a repository, page by page.
+    pub fn tags_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Tag, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .repos()
+                .tags_stream(owner, repo, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::statuses::NewStatus;
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::repos::statuses::{CombinedStatus, Status},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::repos::statuses::Statuses`.
+pub struct Statuses<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Statuses<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Statuses { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a status on a commit. Needs push access.
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &NewStatus,
+    ) -> Result<Status, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .statuses()
+                .create(owner, repo, sha, status),
+        )
+    }
+
+    /// Fetches a page of the statuses of a commit, newest first.
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Status>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .statuses()
+                .list(owner, repo, r#ref, page),
+        )
+    }
+
+    /// Fetches every status of a commit, page by page.
+    pub fn list_iter(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        r#ref: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Status, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .repos()
+                .statuses()
+                .list_stream(owner, repo, r#ref, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches the combined status of a commit.
+    pub fn combined(&self, owner: &str, repo: &str, r#ref: &str) -> Result<CombinedStatus, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .repos()
+                .statuses()
+                .combined(owner, repo, r#ref),
+        )
+    }
+}
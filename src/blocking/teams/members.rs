@@ -0,0 +1,104 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::teams::Role;
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::{
+        teams::{Membership, RoleFilter},
+        users::list::User,
+    },
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::teams::members::Members`.
+pub struct Members<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Members<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Members { client }
+    }
+
+    /// Fetches a page of the members of a team, including the members of nested teams.
+    pub fn list(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: Option<RoleFilter>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<User>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .members()
+                .list(org, team_slug, role, page),
+        )
+    }
+
+    /// Fetches every member of a team, page by page.
+    pub fn list_iter(
+        &self,
+        org: &'a str,
+        team_slug: &'a str,
+        role: Option<RoleFilter>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .teams()
+                .members()
+                .list_stream(org, team_slug, role, page, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches the membership of a user in a team.
+    pub fn get(&self, org: &str, team_slug: &str, username: &str) -> Result<Membership, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .members()
+                .get(org, team_slug, username),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds a user to a team, or changes their role.
+    pub fn set(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+        role: Role,
+    ) -> Result<Membership, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .members()
+                .set(org, team_slug, username, role),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a user from a team. They stay a member of the organization.
+    pub fn remove(&self, org: &str, team_slug: &str, username: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .members()
+                .remove(org, team_slug, username),
+        )
+    }
+}
@@ -0,0 +1,133 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::teams::{NewTeam, UpdateTeam};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::teams::Team,
+    Error,
+};
+use futures::StreamExt;
+
+pub mod members;
+pub mod repos;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod sync;
+use members::Members;
+use repos::Repos;
+#[cfg(any(feature = "auth", doc))]
+use sync::TeamSync;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::teams::Teams`.
+pub struct Teams<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Teams<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Teams { client }
+    }
+
+    /// Members of teams.
+    pub fn members(&self) -> Members<'a> {
+        Members::new(self.client)
+    }
+
+    /// The repositories that teams have access to.
+    pub fn repos(&self) -> Repos<'a> {
+        Repos::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Syncing the members of teams from the groups of an identity provider. Needs GitHub Enterprise Cloud.
+    pub fn sync(&self) -> TeamSync<'a> {
+        TeamSync::new(self.client)
+    }
+
+    /// Fetches a page of the teams of an organization that the current user can see.
+    pub fn list(&self, org: &str, params: Option<PageParams>) -> Result<Vec<Team>, Error> {
+        self.client
+            .block_on(self.client.as_async().teams().list(org, params))
+    }
+
+    /// Fetches every team of an organization that the current user can see, page by page.
+    pub fn list_iter(
+        &self,
+        org: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Team, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(client.as_async().teams().list_stream(org, params, limits));
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a team by its slug.
+    pub fn get(&self, org: &str, team_slug: &str) -> Result<Team, Error> {
+        self.client
+            .block_on(self.client.as_async().teams().get(org, team_slug))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a team. The current authenticated user becomes a maintainer of it.
+    pub fn create(&self, org: &str, team: &NewTeam) -> Result<Team, Error> {
+        self.client
+            .block_on(self.client.as_async().teams().create(org, team))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a team, and returns the updated team.
+    pub fn update(&self, org: &str, team_slug: &str, changes: &UpdateTeam) -> Result<Team, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .update(org, team_slug, changes),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a team, and every team nested under it.
+    pub fn delete(&self, org: &str, team_slug: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().teams().delete(org, team_slug))
+    }
+
+    /// Fetches a page of the teams nested directly under a team.
+    pub fn children(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<Team>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .children(org, team_slug, params),
+        )
+    }
+
+    /// Fetches every team nested directly under a team, page by page.
+    pub fn children_iter(
+        &self,
+        org: &'a str,
+        team_slug: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Team, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .teams()
+                .children_stream(org, team_slug, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+}
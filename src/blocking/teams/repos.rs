@@ -0,0 +1,106 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::NewPermission;
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::repos::Repository,
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::teams::repos::Repos`.
+pub struct Repos<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Repos<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Repos { client }
+    }
+
+    /// Fetches a page of the repositories that a team has access to.
+    pub fn list(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .repos()
+                .list(org, team_slug, params),
+        )
+    }
+
+    /// Fetches every repository that a team has access to, page by page.
+    pub fn list_iter(
+        &self,
+        org: &'a str,
+        team_slug: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Repository, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .teams()
+                .repos()
+                .list_stream(org, team_slug, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if a team has access to a repository.
+    pub fn has_access(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<bool, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .repos()
+                .has_access(org, team_slug, owner, repo),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Gives a team access to a repository, or changes its permission.
+    pub fn set_permission(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        permission: NewPermission,
+    ) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .repos()
+                .set_permission(org, team_slug, owner, repo, permission),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Takes away the access of a team to a repository.
+    pub fn remove(&self, org: &str, team_slug: &str, owner: &str, repo: &str) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .repos()
+                .remove(org, team_slug, owner, repo),
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    blocking::GithubClient,
+    schema::teams::{Group, GroupMapping},
+    Error,
+};
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::teams::sync::TeamSync`.
+pub struct TeamSync<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> TeamSync<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        TeamSync { client }
+    }
+
+    /// Fetches the groups of the identity provider of an organization.
+    pub fn groups(&self, org: &str) -> Result<GroupMapping, Error> {
+        self.client
+            .block_on(self.client.as_async().teams().sync().groups(org))
+    }
+
+    /// Fetches the groups that a team is synced with.
+    pub fn group_mappings(&self, org: &str, team_slug: &str) -> Result<GroupMapping, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .sync()
+                .group_mappings(org, team_slug),
+        )
+    }
+
+    /// Replaces the groups that a team is synced with. An empty list stops syncing the team.
+    pub fn set_group_mappings(
+        &self,
+        org: &str,
+        team_slug: &str,
+        groups: &[Group],
+    ) -> Result<GroupMapping, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .teams()
+                .sync()
+                .set_group_mappings(org, team_slug, groups),
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::users::list,
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::users::blocks::Blocks`.
+pub struct Blocks<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Blocks<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Blocks { client }
+    }
+
+    /// Fetches a page of the users that the current authenticated user has blocked.
+    pub fn list(&self, params: Option<PageParams>) -> Result<Vec<list::User>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().blocks().list(params))
+    }
+
+    /// Fetches every blocked user, page by page.
+    pub fn list_iter(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .blocks()
+                .list_stream(params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if the current authenticated user has blocked a user.
+    pub fn is_blocked(&self, username: &str) -> Result<bool, Error> {
+        self.client
+            .block_on(self.client.as_async().users().blocks().is_blocked(username))
+    }
+
+    /// Blocks a user.
+    pub fn block(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().blocks().block(username))
+    }
+
+    /// Unblocks a user.
+    pub fn unblock(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().blocks().unblock(username))
+    }
+}
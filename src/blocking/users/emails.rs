@@ -0,0 +1,71 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::users::emails::{Email, Visibility},
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::users::emails::Emails`.
+pub struct Emails<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Emails<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Emails { client }
+    }
+
+    /// Fetches a page of the current authenticated user's emails, including the private ones.
+    pub fn list(&self, params: Option<PageParams>) -> Result<Vec<Email>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().emails().list(params))
+    }
+
+    /// Fetches every email, page by page.
+    pub fn list_iter(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Email, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .emails()
+                .list_stream(params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the current authenticated user's public emails.
+    pub fn list_public(&self, params: Option<PageParams>) -> Result<Vec<Email>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().emails().list_public(params))
+    }
+
+    /// Adds emails to the current authenticated user, and returns the added ones.
+    pub fn add(&self, emails: &[&str]) -> Result<Vec<Email>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().emails().add(emails))
+    }
+
+    /// Deletes emails from the current authenticated user.
+    pub fn delete(&self, emails: &[&str]) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().emails().delete(emails))
+    }
+
+    /// Sets whether the primary email is shown on the current authenticated user's profile, and returns the updated emails.
+    pub fn set_primary_visibility(&self, visibility: Visibility) -> Result<Vec<Email>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .emails()
+                .set_primary_visibility(visibility),
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::repos::invitations::Invitation,
+    Error,
+};
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::users::invitations::RepositoryInvitations`.
+pub struct RepositoryInvitations<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> RepositoryInvitations<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        RepositoryInvitations { client }
+    }
+
+    /// Fetches a page of the open invitations of the current authenticated user.
+    pub fn list(&self, params: Option<PageParams>) -> Result<Vec<Invitation>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .repository_invitations()
+                .list(params),
+        )
+    }
+
+    /// Fetches every open invitation, page by page.
+    pub fn list_iter(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<Invitation, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .repository_invitations()
+                .list_stream(params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Accepts an invitation.
+    pub fn accept(&self, invitation_id: usize) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .repository_invitations()
+                .accept(invitation_id),
+        )
+    }
+
+    /// Declines an invitation.
+    pub fn decline(&self, invitation_id: usize) -> Result<(), Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .repository_invitations()
+                .decline(invitation_id),
+        )
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::pagination::Limits;
+use crate::{
+    blocking::GithubClient,
+    pagination::PageParams,
+    schema::users::keys::{GpgKey, SshKey},
+    Error,
+};
+#[cfg(feature = "auth")]
+use futures::StreamExt;
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::users::keys::SshKeys`.
+pub struct SshKeys<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> SshKeys<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        SshKeys { client }
+    }
+
+    /// Fetches a page of the public SSH keys of any user.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<SshKey>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .ssh_keys()
+                .list_for_user(username, params),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the current authenticated user's SSH keys.
+    pub fn list(&self, params: Option<PageParams>) -> Result<Vec<SshKey>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().ssh_keys().list(params))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every SSH key of the current authenticated user, page by page.
+    pub fn list_iter(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<SshKey, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .ssh_keys()
+                .list_stream(params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches one of the current authenticated user's SSH keys.
+    pub fn get(&self, id: usize) -> Result<SshKey, Error> {
+        self.client
+            .block_on(self.client.as_async().users().ssh_keys().get(id))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds an SSH key to the current authenticated user.
+    pub fn create(&self, title: Option<&str>, key: &str) -> Result<SshKey, Error> {
+        self.client
+            .block_on(self.client.as_async().users().ssh_keys().create(title, key))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes one of the current authenticated user's SSH keys.
+    pub fn delete(&self, id: usize) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().ssh_keys().delete(id))
+    }
+}
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::users::keys::GpgKeys`.
+pub struct GpgKeys<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> GpgKeys<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        GpgKeys { client }
+    }
+
+    /// Fetches a page of the GPG keys of any user.
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<GpgKey>, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .gpg_keys()
+                .list_for_user(username, params),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the current authenticated user's GPG keys.
+    pub fn list(&self, params: Option<PageParams>) -> Result<Vec<GpgKey>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().gpg_keys().list(params))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every GPG key of the current authenticated user, page by page.
+    pub fn list_iter(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<GpgKey, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .gpg_keys()
+                .list_stream(params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches one of the current authenticated user's GPG keys.
+    pub fn get(&self, id: usize) -> Result<GpgKey, Error> {
+        self.client
+            .block_on(self.client.as_async().users().gpg_keys().get(id))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds an ASCII armored GPG key to the current authenticated user.
+    pub fn create(&self, armored_public_key: &str) -> Result<GpgKey, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .gpg_keys()
+                .create(armored_public_key),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes one of the current authenticated user's GPG keys.
+    pub fn delete(&self, id: usize) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().gpg_keys().delete(id))
+    }
+}
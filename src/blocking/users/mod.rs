@@ -0,0 +1,273 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::users::{contextual_info, current};
+use crate::{
+    blocking::GithubClient,
+    pagination::{Limits, PageParams},
+    schema::users::{list, single},
+    Error,
+};
+use futures::StreamExt;
+
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod blocks;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod emails;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod invitations;
+pub mod keys;
+#[cfg(any(feature = "auth", doc))]
+use blocks::Blocks;
+#[cfg(any(feature = "auth", doc))]
+use emails::Emails;
+#[cfg(any(feature = "auth", doc))]
+use invitations::RepositoryInvitations;
+use keys::{GpgKeys, SshKeys};
+
+#[non_exhaustive]
+/// The blocking version of `use_github_api::users::Users`.
+pub struct Users<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Users<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Users { client }
+    }
+
+    /// Fetches a list of users.
+    pub fn list(&self, cfg: Option<list::Params>) -> Result<Vec<list::User>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().list(cfg))
+    }
+
+    /// Fetches every user, page by page, following the `since` cursor. Pages are only fetched once the iterator gets to them.
+    /// See `use_github_api::users::Users::list_stream` for how `cfg` and `limits` are used.
+    /// # Examples
+    /// ```rust,ignore
+    /// use use_github_api::pagination::Limits;
+    /// let users = client
+    ///     .users()
+    ///     .list_iter(None, Limits::new().max_items(500))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// ```
+    pub fn list_iter(
+        &self,
+        cfg: Option<list::Params>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(client.as_async().users().list_stream(cfg, limits));
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a specific user.
+    /// If authenticated, it will show a few more fields.
+    /// If the current authenticated user is the same as the user being fetched, a few more fields will exist.
+    /// # Errors
+    /// Will error if the user does not exist.
+    pub fn user(&self, username: &str) -> Result<single::User, Error> {
+        self.client
+            .block_on(self.client.as_async().users().user(username))
+    }
+
+    /// Fetches a page of the users that follow a user.
+    pub fn followers(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::User>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().followers(username, params))
+    }
+
+    /// Fetches every user that follows a user, page by page.
+    pub fn followers_iter(
+        &self,
+        username: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .followers_stream(username, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Fetches a page of the users that a user follows.
+    pub fn following(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::User>, Error> {
+        self.client
+            .block_on(self.client.as_async().users().following(username, params))
+    }
+
+    /// Fetches every user that a user follows, page by page.
+    pub fn following_iter(
+        &self,
+        username: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Iterator<Item = Result<list::User, Error>> + 'a {
+        let client = self.client;
+        let mut stream = Box::pin(
+            client
+                .as_async()
+                .users()
+                .following_stream(username, params, limits),
+        );
+        std::iter::from_fn(move || client.block_on(stream.next()))
+    }
+
+    /// Checks if `username` follows `target`.
+    pub fn follows(&self, username: &str, target: &str) -> Result<bool, Error> {
+        self.client
+            .block_on(self.client.as_async().users().follows(username, target))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches contextual info (like the hovercard you see on github). Requires auth.
+    /// # Errors
+    /// Will error if the user doesn't exist.
+    pub fn contextual_info(
+        &self,
+        username: &str,
+        cfg: Option<contextual_info::Params>,
+    ) -> Result<contextual_info::User, Error> {
+        self.client.block_on(
+            self.client
+                .as_async()
+                .users()
+                .contextual_info(username, cfg),
+        )
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The users blocked by the current authenticated user.
+    pub fn blocks(&self) -> Blocks<'a> {
+        Blocks::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The email addresses of the current authenticated user.
+    pub fn emails(&self) -> Emails<'a> {
+        Emails::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The invitations that the current authenticated user has gotten to collaborate on repositories.
+    pub fn repository_invitations(&self) -> RepositoryInvitations<'a> {
+        RepositoryInvitations::new(self.client)
+    }
+
+    /// Public SSH keys, of the current authenticated user or of anyone else.
+    pub fn ssh_keys(&self) -> SshKeys<'a> {
+        SshKeys::new(self.client)
+    }
+
+    /// GPG keys, of the current authenticated user or of anyone else.
+    pub fn gpg_keys(&self) -> GpgKeys<'a> {
+        GpgKeys::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Checks if the current authenticated user follows `target`.
+    pub fn is_following(&self, target: &str) -> Result<bool, Error> {
+        self.client
+            .block_on(self.client.as_async().users().is_following(target))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Follows a user as the current authenticated user. Needs the `user:follow` scope.
+    pub fn follow(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().follow(username))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Unfollows a user as the current authenticated user. Needs the `user:follow` scope.
+    pub fn unfollow(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .block_on(self.client.as_async().users().unfollow(username))
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches the current authenticated user.
+    pub fn current(&self) -> Result<current::User, Error> {
+        self.client
+            .block_on(self.client.as_async().users().current())
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Updates the profile of the current authenticated user, and returns the updated user.
+    /// # Errors
+    /// Will error if the token does not have the `user` scope, or if `email` is not one of the user's verified emails.
+    pub fn update_current(&self, params: current::UpdateParams) -> Result<current::User, Error> {
+        self.client
+            .block_on(self.client.as_async().users().update_current(params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::blocking_cassette_client as blocking_client;
+
+    #[test]
+    fn single_works() {
+        let client = blocking_client("users/user");
+        let data = client.users().user("mojombo").unwrap();
+        assert_eq!(data.login, "mojombo");
+    }
+
+    #[test]
+    fn list_iter_follows_since() {
+        let client = blocking_client("users/list");
+        let data = client
+            .users()
+            .list_iter(None, Limits::new())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(data.len(), 32);
+        assert_eq!(data[31].id, 48);
+    }
+
+    #[test]
+    fn with_rate_limit_gives_the_rate_limit_of_the_call() {
+        use crate::transport::{MockTransport, Response};
+        use reqwest::{Method, StatusCode};
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/users",
+            Response::new(StatusCode::OK, "[]")
+                .with_header("x-ratelimit-limit", "60")
+                .with_header("x-ratelimit-remaining", "59")
+                .with_header("x-ratelimit-reset", "1620000000")
+                .with_header("x-ratelimit-used", "1"),
+        );
+        let client = GithubClient::from_async(crate::test_utils::mock_client(&mock)).unwrap();
+        let (users, rate_limit) = client.with_rate_limit(|| client.users().list(None));
+        assert!(users.unwrap().is_empty());
+        assert_eq!(rate_limit.unwrap().remaining, 59);
+        let (_, none) = client.with_rate_limit(|| ());
+        assert!(none.is_none());
+    }
+}
@@ -0,0 +1,425 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::checks::{NewCheckRun, Output, UpdateCheckRun};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::checks::{Annotation, CheckRun, CheckRunList, RunListParams},
+    transport::encode_path,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct MoreAnnotations<'b> {
+    output: &'b Output,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Runs<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Runs<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Runs { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a check run.
+    /// GitHub only takes 50 annotations in a request, so if the output has more, the rest are sent with updates after the check run is created.
+    /// # Errors
+    /// Will error if any of the requests fails. The check run is already created by then, with part of the annotations.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        run: &NewCheckRun,
+    ) -> Result<CheckRun, Error> {
+        let mut batches = run
+            .output
+            .as_ref()
+            .map(Output::batches)
+            .unwrap_or_default()
+            .into_iter();
+        let first = NewCheckRun {
+            output: batches.next(),
+            ..run.clone()
+        };
+        let created: CheckRun = self
+            .client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/check-runs", owner, repo),
+                    )
+                    .json(&first)?,
+            )
+            .await?
+            .json()?;
+        self.add_annotations(owner, repo, created, batches).await
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a check run, and returns the updated check run. The annotations in `changes.output` are added to the existing ones.
+    /// GitHub only takes 50 annotations in a request, so if there are more, they are sent with more updates.
+    /// # Errors
+    /// Will error if any of the requests fails. The requests before it have already been applied by then.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: usize,
+        changes: &UpdateCheckRun,
+    ) -> Result<CheckRun, Error> {
+        let mut batches = changes
+            .output
+            .as_ref()
+            .map(Output::batches)
+            .unwrap_or_default()
+            .into_iter();
+        let first = UpdateCheckRun {
+            output: batches.next(),
+            ..changes.clone()
+        };
+        let updated: CheckRun = self
+            .client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/check-runs/{}", owner, repo, check_run_id),
+                    )
+                    .json(&first)?,
+            )
+            .await?
+            .json()?;
+        self.add_annotations(owner, repo, updated, batches).await
+    }
+
+    #[cfg(feature = "auth")]
+    /// Sends the rest of the batches of an output, one update each, and returns the check run as of the last one.
+    async fn add_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        mut run: CheckRun,
+        batches: impl Iterator<Item = Output>,
+    ) -> Result<CheckRun, Error> {
+        for output in batches {
+            run = self
+                .client
+                .send(
+                    self.client
+                        .request(
+                            Method::PATCH,
+                            &format!("/repos/{}/{}/check-runs/{}", owner, repo, run.id),
+                        )
+                        .json(&MoreAnnotations { output: &output })?,
+                )
+                .await?
+                .json()?;
+        }
+        Ok(run)
+    }
+
+    /// Fetches a check run by its id.
+    pub async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: usize,
+    ) -> Result<CheckRun, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/check-runs/{}", owner, repo, check_run_id),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the check runs on a commit. `r#ref` is a branch, tag, or commit SHA.
+    pub async fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        params: Option<RunListParams>,
+        page: Option<PageParams>,
+    ) -> Result<CheckRunList, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!(
+                            "/repos/{}/{}/commits/{}/check-runs",
+                            owner,
+                            repo,
+                            encode_path(r#ref)
+                        ),
+                    )
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every check run on a commit that matches `params`, page by page.
+    pub fn list_for_ref_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        r#ref: &'a str,
+        params: Option<RunListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<CheckRun, Error>> + 'a {
+        let runs = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move {
+                Ok(runs
+                    .list_for_ref(owner, repo, r#ref, params, Some(page))
+                    .await?
+                    .check_runs)
+            }
+        })
+    }
+
+    /// Fetches a page of the check runs in a check suite.
+    pub async fn list_for_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: usize,
+        params: Option<RunListParams>,
+        page: Option<PageParams>,
+    ) -> Result<CheckRunList, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!(
+                            "/repos/{}/{}/check-suites/{}/check-runs",
+                            owner, repo, check_suite_id
+                        ),
+                    )
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the annotations of a check run.
+    pub async fn annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Annotation>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!(
+                            "/repos/{}/{}/check-runs/{}/annotations",
+                            owner, repo, check_run_id
+                        ),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every annotation of a check run, page by page.
+    pub fn annotations_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        check_run_id: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Annotation, Error>> + 'a {
+        let runs = *self;
+        paginate_pages(page, limits, move |page| async move {
+            runs.annotations(owner, repo, check_run_id, Some(page))
+                .await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Asks for a check run to be run again. GitHub sends the app a `check_run` webhook with the `rerequested` action.
+    pub async fn rerequest(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: usize,
+    ) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::POST,
+                &format!(
+                    "/repos/{}/{}/check-runs/{}/rerequest",
+                    owner, repo, check_run_id
+                ),
+            ))
+            .await?
+            .json::<serde_json::Value>()
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::checks::{Annotation, AnnotationLevel, Conclusion, Image, NewCheckRun, Output},
+        test_utils::{cassette_client, mock_client},
+        transport::{MockTransport, Response},
+    };
+    use reqwest::{Method, StatusCode};
+    use serde_json::Value;
+
+    const RUN: &str = r#"{ "id": 4, "node_id": "MDg6Q2hlY2tSdW40", "name": "clippy", "head_sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e", "status": "completed", "output": { "annotations_count": 0 } }"#;
+
+    fn warnings(count: usize) -> Output {
+        Output::new("Clippy", format!("{} warnings", count)).annotations((1..=count).map(|line| {
+            Annotation::new(
+                "src/lib.rs",
+                line,
+                line,
+                AnnotationLevel::Warning,
+                format!("warning {}", line),
+            )
+        }))
+    }
+
+    /// Creates a check run with `output` against a mock, and gives back the `output` of each request that was sent.
+    async fn sent_outputs(output: Output) -> Vec<Value> {
+        let mock = MockTransport::new();
+        mock.on(
+            Method::POST,
+            "/repos/octocat/Hello-World/check-runs",
+            Response::new(StatusCode::CREATED, RUN),
+        );
+        mock.on(
+            Method::PATCH,
+            "/repos/octocat/Hello-World/check-runs/4",
+            Response::new(StatusCode::OK, RUN),
+        );
+        let client = mock_client(&mock);
+        client
+            .checks()
+            .runs()
+            .create(
+                "octocat",
+                "Hello-World",
+                &NewCheckRun::new("clippy", "6dcb09b5b57875f334f61aebed695e2e4193db5e")
+                    .unwrap()
+                    .output(output),
+            )
+            .await
+            .unwrap();
+        mock.requests()
+            .into_iter()
+            .map(|request| {
+                serde_json::from_slice::<Value>(&request.body.unwrap()).unwrap()["output"].clone()
+            })
+            .collect()
+    }
+
+    fn annotation_count(output: &Value) -> usize {
+        output["annotations"].as_array().map_or(0, Vec::len)
+    }
+
+    #[tokio::test]
+    async fn annotations_are_sent_in_batches() {
+        let client = cassette_client("checks/annotations");
+        let runs = client.checks().runs();
+        let run = runs
+            .create(
+                "octocat",
+                "Hello-World",
+                &NewCheckRun::new("clippy", "6dcb09b5b57875f334f61aebed695e2e4193db5e")
+                    .unwrap()
+                    .conclusion(Conclusion::Failure)
+                    .output(warnings(120)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(run.output.annotations_count, 120);
+        runs.rerequest("octocat", "Hello-World", run.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn fifty_annotations_fit_in_one_request() {
+        let outputs = sent_outputs(warnings(50)).await;
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(annotation_count(&outputs[0]), 50);
+    }
+
+    #[tokio::test]
+    async fn outputs_without_annotations_are_sent_once() {
+        let outputs = sent_outputs(warnings(0)).await;
+        assert_eq!(outputs.len(), 1);
+        assert!(outputs[0].get("annotations").is_none());
+    }
+
+    #[tokio::test]
+    async fn images_and_text_are_only_sent_with_the_first_batch() {
+        let output = warnings(120)
+            .text("Run `cargo clippy` to see them.")
+            .image(Image::new(
+                "Warnings over time",
+                "https://example.com/warnings.png",
+            ));
+        let outputs = sent_outputs(output).await;
+        assert_eq!(
+            outputs.iter().map(annotation_count).collect::<Vec<_>>(),
+            vec![50, 50, 20]
+        );
+        assert_eq!(outputs[0]["images"].as_array().unwrap().len(), 1);
+        assert!(outputs[0]["text"].is_string());
+        for output in &outputs[1..] {
+            assert_eq!(output["title"], "Clippy");
+            assert_eq!(output["summary"], "120 warnings");
+            assert!(output.get("images").is_none());
+            assert!(output.get("text").is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn refs_are_encoded() {
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/repos/octocat/Hello-World/commits/feature/100%25/check-runs",
+            Response::new(StatusCode::OK, r#"{ "total_count": 0, "check_runs": [] }"#),
+        );
+        let client = mock_client(&mock);
+        let runs = client
+            .checks()
+            .runs()
+            .list_for_ref("octocat", "Hello-World", "feature/100%", None, None)
+            .await
+            .unwrap();
+        assert_eq!(runs.total_count, 0);
+    }
+}
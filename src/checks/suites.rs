@@ -0,0 +1,155 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::checks::{CheckSuite, CheckSuiteList, SuiteListParams},
+    transport::encode_path,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct HeadSha<'b> {
+    head_sha: &'b str,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Suites<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Suites<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Suites { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a check suite for a commit. Only needed when automatic creation of check suites is turned off for the app.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        head_sha: &str,
+    ) -> Result<CheckSuite, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/check-suites", owner, repo),
+                    )
+                    .json(&HeadSha { head_sha })?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a check suite by its id.
+    pub async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: usize,
+    ) -> Result<CheckSuite, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/check-suites/{}", owner, repo, check_suite_id),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the check suites on a commit. `r#ref` is a branch, tag, or commit SHA.
+    pub async fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        params: Option<SuiteListParams>,
+        page: Option<PageParams>,
+    ) -> Result<CheckSuiteList, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!(
+                            "/repos/{}/{}/commits/{}/check-suites",
+                            owner,
+                            repo,
+                            encode_path(r#ref)
+                        ),
+                    )
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every check suite on a commit that matches `params`, page by page.
+    pub fn list_for_ref_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        r#ref: &'a str,
+        params: Option<SuiteListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<CheckSuite, Error>> + 'a {
+        let suites = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move {
+                Ok(suites
+                    .list_for_ref(owner, repo, r#ref, params, Some(page))
+                    .await?
+                    .check_suites)
+            }
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Asks for every check run in a check suite to be run again. GitHub sends the app a `check_suite` webhook with the `rerequested` action.
+    pub async fn rerequest(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: usize,
+    ) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::POST,
+                &format!(
+                    "/repos/{}/{}/check-suites/{}/rerequest",
+                    owner, repo, check_suite_id
+                ),
+            ))
+            .await?
+            .json::<serde_json::Value>()
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn rerequests_a_suite() {
+        let client = cassette_client("checks/suites_rerequest");
+        client
+            .checks()
+            .suites()
+            .rerequest("octocat", "Hello-World", 5)
+            .await
+            .unwrap();
+    }
+}
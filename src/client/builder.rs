@@ -1,9 +1,7 @@
-use std::error::Error as StdError;
-
 // This should work without this `cfg`, but the doctest fails without it (even though it is ignored).
 #[cfg(feature = "auth")]
 use crate::CreationError;
-use crate::GithubClient;
+use crate::{Error, GithubClient, RetryPolicy};
 
 #[non_exhaustive]
 /// A `GithubClient` builder.
@@ -17,14 +15,15 @@ use crate::GithubClient;
 /// let client = GithubClientBuilder::new().auth("ghp_kajshdkja").build().unwrap();
 /// ```
 #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
-pub struct GithubClientBuilder<'a> {
+pub struct GithubClientBuilder {
     #[cfg(feature = "enterprise")]
-    base_url: Option<&'a str>,
+    base_url: Option<String>,
     #[cfg(feature = "auth")]
-    auth_token: Option<&'a str>,
+    auth_token: Option<String>,
+    retry_policy: RetryPolicy,
 }
 
-impl<'a> GithubClientBuilder<'a> {
+impl GithubClientBuilder {
     /// Creates a new `GithubClientBuilder`.
     pub fn new() -> Self {
         Self {
@@ -32,27 +31,31 @@ impl<'a> GithubClientBuilder<'a> {
             auth_token: None,
             #[cfg(feature = "enterprise")]
             base_url: None,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
     /// Builds the builder and returns a client.
     /// # Errors
     /// If either the auth token or the base url is missing, this will error out.
-    pub fn build(&self) -> Result<GithubClient<'a>, Box<dyn StdError>> {
+    pub fn build(&self) -> Result<GithubClient, Error> {
         #[cfg(all(feature = "auth", feature = "enterprise"))]
-        return match self.auth_token {
+        let client = match &self.auth_token {
             None => Err(CreationError::auth_token_not_provided().into()),
-            Some(token) => match self.base_url {
+            Some(token) => match &self.base_url {
                 None => Err(CreationError::base_url_not_provided().into()),
                 Some(base_url) => GithubClient::new(base_url, token),
             },
         };
         #[cfg(feature = "auth")]
         #[cfg(not(feature = "enterprise"))]
-        return match self.auth_token {
+        let client = match &self.auth_token {
             None => Err(CreationError::auth_token_not_provided().into()),
             Some(token) => GithubClient::new(token),
         };
+        let mut client = client?;
+        client.retry_policy = self.retry_policy;
+        Ok(client)
     }
 
     #[cfg(any(feature = "auth", doc))]
@@ -67,8 +70,8 @@ impl<'a> GithubClientBuilder<'a> {
     /// // Build client and do stuff
     /// # }
     /// ```
-    pub fn auth(&mut self, auth_token: &'a str) -> &mut Self {
-        self.auth_token = Some(auth_token);
+    pub fn auth(&mut self, auth_token: &str) -> &mut Self {
+        self.auth_token = Some(auth_token.to_owned());
         self
     }
 
@@ -84,13 +87,29 @@ impl<'a> GithubClientBuilder<'a> {
     /// // Build client and do stuff
     /// # }
     /// ```
-    pub fn base_url(&mut self, base_url: &'a str) -> &mut Self {
-        self.base_url = Some(base_url);
+    pub fn base_url(&mut self, base_url: &str) -> &mut Self {
+        self.base_url = Some(base_url.to_owned());
+        self
+    }
+
+    /// Sets the `RetryPolicy` used for every request. Defaults to `RetryPolicy::default()`.
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "auth")]
+    /// # {
+    /// # use use_github_api::{GithubClientBuilder, RetryPolicy};
+    /// let mut builder = GithubClientBuilder::new();
+    /// let builder = builder.retry_policy(RetryPolicy::new().max_attempts(5));
+    /// // Build client and do stuff
+    /// # }
+    /// ```
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
         self
     }
 }
 
-impl<'a> Default for GithubClientBuilder<'a> {
+impl Default for GithubClientBuilder {
     fn default() -> Self {
         Self::new()
     }
@@ -113,7 +132,7 @@ mod tests {
     fn sets_auth() {
         let token = "Some token";
         assert_eq!(
-            GithubClientBuilder::new().auth(token).auth_token,
+            GithubClientBuilder::new().auth(token).auth_token.as_deref(),
             Some(token)
         );
     }
@@ -123,11 +142,23 @@ mod tests {
     fn sets_base_url() {
         let base_url = "something.com";
         assert_eq!(
-            GithubClientBuilder::new().base_url(base_url).base_url,
+            GithubClientBuilder::new()
+                .base_url(base_url)
+                .base_url
+                .as_deref(),
             Some(base_url)
         );
     }
 
+    #[test]
+    fn sets_retry_policy() {
+        let policy = RetryPolicy::none();
+        assert_eq!(
+            GithubClientBuilder::new().retry_policy(policy).retry_policy,
+            policy
+        );
+    }
+
     #[test]
     #[should_panic(expected = "CreationError { kind: AuthTokenNotProvided }")]
     fn err_on_no_token() {
@@ -155,8 +186,12 @@ mod tests {
             .base_url("https://something.something.com/api/v3")
             .build()
             .expect("Should build client");
-        assert_eq!(client.auth_token, FAKE_TOKEN);
+        assert_eq!(
+            client.default_headers.get("Authorization").unwrap(),
+            &format!("token {}", FAKE_TOKEN)
+        );
         #[cfg(feature = "enterprise")]
-        assert_eq!(client.base_url, "https://something.something.com/api/v3");
+        assert_eq!(&*client.base_url, "https://something.something.com/api/v3");
+        assert_eq!(client.retry_policy, RetryPolicy::default());
     }
 }
@@ -1,11 +1,28 @@
-use crate::users::Users;
 #[cfg(feature = "enterprise")]
 use crate::CreationError;
+use crate::{
+    checks::Checks,
+    git::Git,
+    issues::Issues,
+    orgs::Orgs,
+    pulls::Pulls,
+    rate_limit::RateLimits,
+    repos::Repos,
+    schema::rate_limit::RateLimit,
+    teams::Teams,
+    transport::{Request, ReqwestTransport, Response, StreamingResponse, Transport},
+    users::Users,
+    Error,
+};
 use reqwest::{
-    header::{HeaderMap, ACCEPT},
-    Client,
+    header::{HeaderMap, ACCEPT, USER_AGENT},
+    Method,
+};
+use std::{
+    cell::RefCell,
+    future::Future,
+    sync::{Arc, Mutex},
 };
-use std::error::Error as StdError;
 
 #[cfg(any(feature = "auth", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
@@ -14,41 +31,35 @@ mod builder;
 #[cfg(any(feature = "auth", doc))]
 pub use builder::GithubClientBuilder;
 
-pub(crate) mod macros {
-    #[macro_export]
-    #[doc(hidden)]
-    macro_rules! url {
-        ($self:expr, $i:expr) => {
-            format!("{}{}", $self.client.base_url, $i)
-        };
-        ($self:expr, $i:expr, $($arg:expr),*) => {
-            format!("{}{}", $self.client.base_url, format!($i, $($arg),*))
-        };
-    }
+mod response;
+
+mod retry;
+pub use retry::RetryPolicy;
+
+tokio::task_local! {
+    /// The rate limit of the latest response, while a future is run by `GithubClient::with_rate_limit`.
+    static SCOPED_RATE_LIMIT: RefCell<Option<RateLimit>>;
 }
 
-#[derive(Debug)]
-/// Holds the reqwest client, auth token, base url, headers, user agent, etc.
-pub struct GithubClient<'a> {
-    pub(crate) base_url: &'a str,
-    pub(crate) reqwest_client: Client,
-    #[cfg(feature = "auth")]
-    #[cfg(test)]
-    pub(crate) auth_token: &'a str,
-    #[cfg(feature = "auth")]
-    #[cfg(not(test))]
-    auth_token: &'a str,
+#[derive(Debug, Clone)]
+/// Holds the transport, auth token, base url, headers, user agent, etc.
+/// It owns all of its data, so it can be stored anywhere (like the state of a web server) and sent to other tasks.
+/// Cloning it is cheap, and clones share the connection pool and the rate limit info.
+pub struct GithubClient {
+    pub(crate) base_url: Arc<str>,
+    transport: Arc<dyn Transport>,
     pub(crate) default_headers: HeaderMap,
-    user_agent: &'a str,
+    last_rate_limit: Arc<Mutex<Option<RateLimit>>>,
+    pub(crate) retry_policy: RetryPolicy,
 }
 
-impl<'a> GithubClient<'a> {
+impl GithubClient {
     /// Creates a new `GithubClient` which can be used to send requests.
     /// # Signature
     /// The signatures of the function changes when the features are changed.
-    /// - When no features are enabled, the signature is `fn () -> Result<GithubClient<'a>, Box<dyn StdError>>`
-    /// - When the auth feature is enabled, the signature is `fn (auth_token: &'a str) -> Result<GithubClient<'a>, Box<dyn StdError>>`
-    /// - When the enterprise feature is enabled, the signature is fn `fn (base_url: &'a str, auth_token: &'a str) -> Result<GithubClient<'a>, Box<dyn StdError>>`
+    /// - When no features are enabled, the signature is `fn () -> Result<GithubClient, Error>`
+    /// - When the auth feature is enabled, the signature is `fn (auth_token: &str) -> Result<GithubClient, Error>`
+    /// - When the enterprise feature is enabled, the signature is fn `fn (base_url: &str, auth_token: &str) -> Result<GithubClient, Error>`
     /// # Arguments
     /// ## Base URL
     /// The base url can be like this: `https://somehostfor.github.enterprise.org/api/v3`.
@@ -57,7 +68,6 @@ impl<'a> GithubClient<'a> {
     /// If using a PAT (personal access token), you can obtain one from <https://github.com/settings/tokens>.
     /// # Errors
     /// Will error if the protocol is not `http://` or `https://`, and will also error if the base URL does not include `/api/v3`.
-    /// Will also error if the reqwest client fails to build.
     /// # Examples
     /// ```rust
     /// use use_github_api::GithubClient;
@@ -67,9 +77,9 @@ impl<'a> GithubClient<'a> {
     /// // do something with `client`
     /// ```
     pub fn new(
-        #[cfg(feature = "enterprise")] base_url: &'a str,
-        #[cfg(feature = "auth")] auth_token: &'a str,
-    ) -> Result<GithubClient<'a>, Box<dyn StdError>> {
+        #[cfg(feature = "enterprise")] base_url: &str,
+        #[cfg(feature = "auth")] auth_token: &str,
+    ) -> Result<GithubClient, Error> {
         #[cfg(feature = "enterprise")]
         if !(base_url.starts_with("https://") || base_url.starts_with("http://")) {
             return Err(CreationError::base_url_without_protocol().into());
@@ -85,36 +95,170 @@ impl<'a> GithubClient<'a> {
             headers.insert("Authorization", token_header);
         }
         const UA: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
-        let client = Client::builder()
-            .default_headers(headers.clone())
-            .user_agent(UA)
-            .build()?;
+        headers.insert(USER_AGENT, UA.parse().unwrap());
         Ok(Self {
             #[cfg(feature = "enterprise")]
-            base_url,
+            base_url: base_url.into(),
             #[cfg(not(feature = "enterprise"))]
-            base_url: "https://api.github.com",
-            reqwest_client: client,
-            #[cfg(feature = "auth")]
-            auth_token,
+            base_url: "https://api.github.com".into(),
+            transport: Arc::new(ReqwestTransport::new()),
             default_headers: headers,
-            user_agent: UA,
+            last_rate_limit: Arc::new(Mutex::new(None)),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
     #[cfg(feature = "auth")]
     /// Gives a `GithubClientBuilder`, same as using `GithubClientBuilder::new()`.
-    pub fn builder() -> GithubClientBuilder<'a> {
+    pub fn builder() -> GithubClientBuilder {
         GithubClientBuilder::new()
     }
 
+    /// Replaces the `Transport` that requests are sent through. The default is a `ReqwestTransport`.
+    /// # Examples
+    /// ```rust
+    /// # #[cfg(feature = "auth")]
+    /// # #[cfg(not(feature = "enterprise"))]
+    /// # {
+    /// use use_github_api::{transport::MockTransport, GithubClient};
+    /// let client = GithubClient::new("ghp_akjsdh").unwrap().with_transport(MockTransport::new());
+    /// # }
+    /// ```
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
     pub fn users(&self) -> Users<'_> {
-        Users::new(&self)
+        Users::new(self)
+    }
+
+    pub fn rate_limit(&self) -> RateLimits<'_> {
+        RateLimits::new(self)
+    }
+
+    pub fn repos(&self) -> Repos<'_> {
+        Repos::new(self)
+    }
+
+    pub fn issues(&self) -> Issues<'_> {
+        Issues::new(self)
+    }
+
+    pub fn pulls(&self) -> Pulls<'_> {
+        Pulls::new(self)
+    }
+
+    pub fn checks(&self) -> Checks<'_> {
+        Checks::new(self)
+    }
+
+    pub fn git(&self) -> Git<'_> {
+        Git::new(self)
+    }
+
+    pub fn orgs(&self) -> Orgs<'_> {
+        Orgs::new(self)
+    }
+
+    pub fn teams(&self) -> Teams<'_> {
+        Teams::new(self)
+    }
+
+    /// The rate limit info from the `X-RateLimit-*` headers of the latest response, if there has been one.
+    /// Unlike `rate_limit().get()`, this does not send a request.
+    /// Clones share this value, so with requests running at the same time, use `with_rate_limit` to know which response it came from.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.last_rate_limit.lock().unwrap().clone()
+    }
+
+    /// Runs `future` (usually a call to an endpoint), and gives back its output along with the rate limit info of the latest response it got.
+    /// Other requests that run at the same time do not affect it, even on clones of the client.
+    /// # Examples
+    /// ```rust,ignore
+    /// let (user, rate_limit) = client.with_rate_limit(client.users().user("octocat")).await;
+    /// let user = user?;
+    /// println!("{} requests left", rate_limit.unwrap().remaining);
+    /// ```
+    pub async fn with_rate_limit<F: Future>(&self, future: F) -> (F::Output, Option<RateLimit>) {
+        SCOPED_RATE_LIMIT
+            .scope(RefCell::new(None), async {
+                let output = future.await;
+                (
+                    output,
+                    SCOPED_RATE_LIMIT.with(|rate_limit| rate_limit.take()),
+                )
+            })
+            .await
+    }
+
+    /// Creates a request for a path, with the base URL and default headers filled in.
+    pub(crate) fn request(&self, method: Method, path: &str) -> Request {
+        let mut request = Request::new(method, &self.base_url, path);
+        request.headers = self.default_headers.clone();
+        request
+    }
+
+    /// Sends a request through the transport, keeping track of the rate limit along the way.
+    /// Failed attempts are retried according to the `RetryPolicy`.
+    pub(crate) async fn send(&self, request: Request) -> Result<Response, Error> {
+        let mut attempt = 1;
+        loop {
+            let result = self.transport.send(request.clone()).await;
+            if let Ok(response) = &result {
+                self.track_rate_limit(response.rate_limit());
+                crate::pagination::set_next_link(response.next_link());
+            }
+            match self.retry_policy.delay(&request.method, attempt, &result) {
+                Some(delay) => {
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return result,
+            }
+        }
+    }
+
+    /// Like `send`, but gives back the body of a successful response as it comes in.
+    /// Unsuccessful responses are read whole, retried like with `send`, and turned into an error in the end.
+    pub(crate) async fn send_streaming(
+        &self,
+        request: Request,
+    ) -> Result<StreamingResponse, Error> {
+        let mut attempt = 1;
+        loop {
+            let result = match self.transport.send_streaming(request.clone()).await {
+                Ok(response) if response.status.is_success() => {
+                    self.track_rate_limit(RateLimit::from_headers(&response.headers));
+                    return Ok(response);
+                }
+                Ok(response) => response.read().await,
+                Err(err) => Err(err),
+            };
+            if let Ok(response) = &result {
+                self.track_rate_limit(response.rate_limit());
+            }
+            match self.retry_policy.delay(&request.method, attempt, &result) {
+                Some(delay) => {
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return Err(result.map_or_else(|err| err, Response::into_error)),
+            }
+        }
+    }
+
+    fn track_rate_limit(&self, rate_limit: Option<RateLimit>) {
+        if let Some(rate_limit) = rate_limit {
+            let _ = SCOPED_RATE_LIMIT
+                .try_with(|scoped| *scoped.borrow_mut() = Some(rate_limit.clone()));
+            *self.last_rate_limit.lock().unwrap() = Some(rate_limit);
+        }
     }
 }
 
 #[cfg(not(feature = "auth"))]
-impl<'a> Default for GithubClient<'a> {
+impl Default for GithubClient {
     fn default() -> Self {
         Self::new().expect("Error while creating default client")
     }
@@ -137,10 +281,8 @@ mod tests {
             FAKE_TOKEN,
         )
         .expect("Should build client");
-        #[cfg(feature = "auth")]
-        assert_eq!(client.auth_token, FAKE_TOKEN);
         #[cfg(feature = "enterprise")]
-        assert_eq!(client.base_url, "https://something.com/api/v3");
+        assert_eq!(&*client.base_url, "https://something.com/api/v3");
         #[cfg(feature = "auth")]
         assert_eq!(
             client.default_headers.get("Authorization"),
@@ -151,9 +293,183 @@ mod tests {
             )
         );
         assert_eq!(
-            client.user_agent,
-            format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+            client.default_headers.get(USER_AGENT).unwrap(),
+            &format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[tokio::test]
+    async fn send_retries_scripted_failures() {
+        use crate::test_utils::fake_server;
+        use std::time::Duration;
+        let (base_url, hits) = fake_server(vec![
+            None,
+            Some("HTTP/1.1 502 Bad Gateway\r\ncontent-length: 0\r\n\r\n"),
+            Some("HTTP/1.1 403 Forbidden\r\nretry-after: 0\r\ncontent-length: 2\r\n\r\n{}"),
+            Some("HTTP/1.1 200 OK\r\nx-ratelimit-limit: 5000\r\nx-ratelimit-remaining: 4999\r\nx-ratelimit-reset: 1620000000\r\nx-ratelimit-used: 1\r\ncontent-length: 2\r\n\r\n[]"),
+        ])
+        .await;
+        let mut client = GithubClient::new(
+            #[cfg(feature = "enterprise")]
+            "https://something.com/api/v3",
+            #[cfg(feature = "auth")]
+            FAKE_TOKEN,
+        )
+        .unwrap();
+        client.retry_policy = RetryPolicy::new()
+            .max_attempts(4)
+            .base_delay(Duration::from_millis(1));
+        client.base_url = base_url.into();
+        let response = client
+            .send(client.request(Method::GET, "/users"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"[]");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 4);
+        assert_eq!(client.last_rate_limit().unwrap().remaining, 4999);
+    }
+
+    #[tokio::test]
+    async fn send_gives_up_after_max_attempts() {
+        use crate::test_utils::fake_server;
+        use std::time::Duration;
+        let (base_url, hits) = fake_server(vec![
+            Some("HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n"),
+            Some("HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n"),
+            Some("HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n[]"),
+        ])
+        .await;
+        let mut client = GithubClient::new(
+            #[cfg(feature = "enterprise")]
+            "https://something.com/api/v3",
+            #[cfg(feature = "auth")]
+            FAKE_TOKEN,
+        )
+        .unwrap();
+        client.retry_policy = RetryPolicy::new()
+            .max_attempts(2)
+            .base_delay(Duration::from_millis(1));
+        client.base_url = base_url.into();
+        let response = client
+            .send(client.request(Method::GET, "/users"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn send_does_not_resend_a_failed_post() {
+        use crate::test_utils::fake_server;
+        use std::time::Duration;
+        let (base_url, hits) = fake_server(vec![
+            Some("HTTP/1.1 502 Bad Gateway\r\ncontent-length: 0\r\n\r\n"),
+            Some("HTTP/1.1 201 Created\r\ncontent-length: 2\r\n\r\n{}"),
+        ])
+        .await;
+        let mut client = GithubClient::new(
+            #[cfg(feature = "enterprise")]
+            "https://something.com/api/v3",
+            #[cfg(feature = "auth")]
+            FAKE_TOKEN,
+        )
+        .unwrap();
+        client.retry_policy = RetryPolicy::new()
+            .max_attempts(3)
+            .base_delay(Duration::from_millis(1));
+        client.base_url = base_url.into();
+        let response = client
+            .send(client.request(Method::POST, "/repos/octocat/Hello-World/issues"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 502);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn sends_default_headers_through_the_transport() {
+        use crate::transport::MockTransport;
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/users",
+            Response::new(reqwest::StatusCode::OK, "[]"),
+        );
+        let client = crate::test_utils::mock_client(&mock);
+        client.users().list(None).await.unwrap();
+        let request = &mock.requests()[0];
+        assert_eq!(
+            request.headers.get(USER_AGENT),
+            client.default_headers.get(USER_AGENT)
+        );
+        #[cfg(feature = "auth")]
+        assert_eq!(
+            request.headers.get("Authorization").unwrap(),
+            &format!("token {}", FAKE_TOKEN)
+        );
+    }
+
+    #[test]
+    fn client_is_owned_and_shareable() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<GithubClient>();
+    }
+
+    #[tokio::test]
+    async fn clones_share_rate_limit() {
+        use crate::test_utils::fake_server;
+        let (base_url, _) = fake_server(vec![Some("HTTP/1.1 200 OK\r\nx-ratelimit-limit: 5000\r\nx-ratelimit-remaining: 4998\r\nx-ratelimit-reset: 1620000000\r\nx-ratelimit-used: 2\r\ncontent-length: 2\r\n\r\n[]")]).await;
+        let mut client = GithubClient::new(
+            #[cfg(feature = "enterprise")]
+            "https://something.com/api/v3",
+            #[cfg(feature = "auth")]
+            FAKE_TOKEN,
+        )
+        .unwrap();
+        client.base_url = base_url.into();
+        let clone = client.clone();
+        tokio::spawn(async move {
+            clone
+                .send(clone.request(Method::GET, "/users"))
+                .await
+                .unwrap();
+        })
+        .await
+        .unwrap();
+        assert_eq!(client.last_rate_limit().unwrap().remaining, 4998);
+    }
+
+    #[tokio::test]
+    async fn with_rate_limit_belongs_to_its_own_call() {
+        use crate::transport::MockTransport;
+        let rate_limited = |remaining: &str| {
+            Response::new(reqwest::StatusCode::OK, "[]")
+                .with_header("x-ratelimit-limit", "5000")
+                .with_header("x-ratelimit-remaining", remaining)
+                .with_header("x-ratelimit-reset", "1620000000")
+                .with_header("x-ratelimit-used", "1")
+        };
+        let mock = MockTransport::new();
+        mock.on(Method::GET, "/users", rate_limited("4999"));
+        mock.on(
+            Method::GET,
+            "/users/mojombo/followers",
+            rate_limited("4998"),
+        );
+        let client = crate::test_utils::mock_client(&mock);
+        let api = client.users();
+        let ((users, rate_limit), (followers, followers_rate_limit)) = futures::join!(
+            client.with_rate_limit(api.list(None)),
+            client.with_rate_limit(api.followers("mojombo", None)),
         );
+        users.unwrap();
+        followers.unwrap();
+        assert_eq!(rate_limit.unwrap().remaining, 4999);
+        assert_eq!(followers_rate_limit.unwrap().remaining, 4998);
+
+        let (_, none) = client.with_rate_limit(async {}).await;
+        assert!(none.is_none());
     }
 
     #[test]
@@ -0,0 +1,277 @@
+use reqwest::{header::LINK, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::{from_slice, from_str};
+
+use crate::{
+    schema::{rate_limit::RateLimit, GitHubError},
+    transport::Response,
+    Error,
+};
+
+impl Response {
+    /// The rate limit info sent along with this response, if any.
+    pub(crate) fn rate_limit(&self) -> Option<RateLimit> {
+        RateLimit::from_headers(&self.headers)
+    }
+
+    /// The URL of the `rel="next"` entry of the `Link` header, which paginated endpoints send on every page but the last.
+    pub(crate) fn next_link(&self) -> Option<String> {
+        self.headers
+            .get(LINK)?
+            .to_str()
+            .ok()?
+            .split(',')
+            .find_map(|entry| {
+                let (url, params) = entry.split_once(';')?;
+                params
+                    .split(';')
+                    .any(|param| param.trim() == r#"rel="next""#)
+                    .then(|| {
+                        url.trim()
+                            .trim_start_matches('<')
+                            .trim_end_matches('>')
+                            .to_owned()
+                    })
+            })
+    }
+
+    /// Checks the status, and only decodes the body if it is a success.
+    /// A `204 No Content` is decoded as `null`, so endpoints without a body can use `()`.
+    pub(crate) fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        if !self.status.is_success() {
+            return Err(self.into_error());
+        }
+        if self.status == StatusCode::NO_CONTENT || self.body.is_empty() {
+            return Ok(from_str("null")?);
+        }
+        Ok(from_slice(&self.body)?)
+    }
+
+    /// For endpoints that answer a yes/no question with the status: `204 No Content` is yes, and `404 Not Found` is no.
+    pub(crate) fn exists(self) -> Result<bool, Error> {
+        match self.status {
+            StatusCode::NO_CONTENT => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => self.json::<serde_json::Value>().map(|_| true),
+        }
+    }
+
+    /// Maps an unsuccessful response to an error, based on the status.
+    pub(crate) fn into_error(self) -> Error {
+        let status = self.status;
+        if status == StatusCode::NOT_MODIFIED {
+            return Error::NotModified;
+        }
+        if status.is_server_error() {
+            return Error::Server {
+                status,
+                body: String::from_utf8_lossy(&self.body).into_owned(),
+            };
+        }
+        let error = match from_slice::<GitHubError>(&self.body) {
+            Ok(error) => error,
+            // Some statuses are clear enough on their own, even when the body is not from GitHub.
+            Err(_) => match status.canonical_reason() {
+                Some(reason) if status.is_client_error() => GitHubError {
+                    message: reason.to_owned(),
+                    documentation_url: None,
+                    errors: Vec::new(),
+                },
+                _ => {
+                    return Error::Http {
+                        status,
+                        body: String::from_utf8_lossy(&self.body).into_owned(),
+                    }
+                }
+            },
+        };
+        match status {
+            StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS if self.is_rate_limited() => {
+                Error::RateLimited {
+                    error,
+                    retry_after: self.retry_after(),
+                    reset: self.rate_limit().map(|rate_limit| rate_limit.reset),
+                }
+            }
+            #[cfg(feature = "auth")]
+            StatusCode::UNAUTHORIZED => Error::BadCredentials(error),
+            StatusCode::FORBIDDEN => Error::Forbidden(error),
+            StatusCode::NOT_FOUND => Error::NotFound(error),
+            StatusCode::CONFLICT => Error::Conflict(error),
+            StatusCode::GONE => Error::Gone(error),
+            StatusCode::UNPROCESSABLE_ENTITY => Error::Unprocessable(error),
+            status => Error::Api { status, error },
+        }
+    }
+
+    /// Secondary rate limits come with `Retry-After`, primary ones with no requests remaining.
+    /// A `429` is always a rate limit.
+    fn is_rate_limited(&self) -> bool {
+        self.status == StatusCode::TOO_MANY_REQUESTS
+            || self.headers.contains_key("retry-after")
+            || self
+                .rate_limit()
+                .is_some_and(|rate_limit| rate_limit.remaining == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{users::single, ErrorEntry};
+
+    fn response(status: u16, headers: &[(&str, &str)], body: &str) -> Response {
+        headers.iter().fold(
+            Response::new(StatusCode::from_u16(status).unwrap(), body),
+            |response, (name, value)| response.with_header(name, value),
+        )
+    }
+
+    const NOT_FOUND: &str =
+        r#"{ "message": "Not Found", "documentation_url": "https://docs.github.com/rest" }"#;
+
+    #[test]
+    #[cfg(feature = "auth")]
+    fn returns_bad_creds() {
+        let body = r#"{ "message": "Bad credentials", "documentation_url": "https://docs.github.com/rest" }"#;
+        let err = response(401, &[], body).json::<single::User>().unwrap_err();
+        assert!(matches!(err, Error::BadCredentials(error) if error.message == "Bad credentials"));
+    }
+
+    #[test]
+    fn exists_maps_no_content_and_not_found() {
+        assert!(response(204, &[], "").exists().unwrap());
+        assert!(!response(404, &[], NOT_FOUND).exists().unwrap());
+        assert!(matches!(
+            response(403, &[], NOT_FOUND).exists(),
+            Err(Error::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn returns_not_found() {
+        let err = response(404, &[], NOT_FOUND)
+            .json::<single::User>()
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound(_)));
+    }
+
+    #[test]
+    fn returns_rate_limited() {
+        let body = r#"{ "message": "You have exceeded a secondary rate limit." }"#;
+        let err = response(403, &[("retry-after", "60")], body)
+            .json::<single::User>()
+            .unwrap_err();
+        assert!(matches!(err, Error::RateLimited { .. }));
+    }
+
+    #[test]
+    fn keeps_the_errors_array() {
+        let body = r#"{
+            "message": "Validation Failed",
+            "errors": [{ "resource": "Issue", "field": "title", "code": "missing_field" }],
+            "documentation_url": "https://docs.github.com/rest"
+        }"#;
+        let err = response(422, &[], body).json::<single::User>().unwrap_err();
+        match err {
+            Error::Unprocessable(error) => match &error.errors[0] {
+                ErrorEntry::Detail(detail) => assert_eq!(detail.field.as_deref(), Some("title")),
+                entry => panic!("Expected an error detail, got {:?}", entry),
+            },
+            _ => panic!("Expected an unprocessable error, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn keeps_the_message_when_errors_are_strings() {
+        let body = r#"{
+            "message": "Validation Failed",
+            "errors": ["Could not resolve to a node with the global id of 'nope'."],
+            "documentation_url": "https://docs.github.com/rest"
+        }"#;
+        let err = response(422, &[], body).json::<single::User>().unwrap_err();
+        match err {
+            Error::Unprocessable(error) => {
+                assert_eq!(error.message, "Validation Failed");
+                assert_eq!(
+                    error.errors[0].message(),
+                    Some("Could not resolve to a node with the global id of 'nope'.")
+                );
+            }
+            _ => panic!("Expected an unprocessable error, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn maps_statuses_to_variants() {
+        let error = r#"{ "message": "Something" }"#;
+        assert!(matches!(
+            response(403, &[], error).json::<()>().unwrap_err(),
+            Error::Forbidden(_)
+        ));
+        assert!(matches!(
+            response(409, &[], error).json::<()>().unwrap_err(),
+            Error::Conflict(_)
+        ));
+        assert!(matches!(
+            response(410, &[], error).json::<()>().unwrap_err(),
+            Error::Gone(_)
+        ));
+        assert!(matches!(
+            response(429, &[], error).json::<()>().unwrap_err(),
+            Error::RateLimited { .. }
+        ));
+        assert!(matches!(
+            response(418, &[], error).json::<()>().unwrap_err(),
+            Error::Api { status, .. } if status == StatusCode::IM_A_TEAPOT
+        ));
+    }
+
+    #[test]
+    fn does_not_decode_error_bodies_as_data() {
+        // This would decode fine as a `contextual_info::User`, but it is still an error.
+        let body = r#"{ "message": "Not Found", "contexts": [] }"#;
+        let err = response(404, &[], body)
+            .json::<crate::schema::users::contextual_info::User>()
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound(_)));
+    }
+
+    #[test]
+    fn handles_html_error_pages() {
+        let html = "<html>Bad gateway</html>";
+        let err = response(502, &[], html).json::<single::User>().unwrap_err();
+        assert!(
+            matches!(err, Error::Server { status, body } if status == StatusCode::BAD_GATEWAY && body == html)
+        );
+        let err = response(404, &[], html).json::<single::User>().unwrap_err();
+        assert!(matches!(err, Error::NotFound(error) if error.message == "Not Found"));
+        let err = response(307, &[], html).json::<single::User>().unwrap_err();
+        assert!(
+            matches!(err, Error::Http { status, .. } if status == StatusCode::TEMPORARY_REDIRECT)
+        );
+    }
+
+    #[test]
+    fn finds_the_next_link() {
+        let link = r#"<https://api.github.com/user/repos?page=3&per_page=100>; rel="next", <https://api.github.com/user/repos?page=50&per_page=100>; rel="last""#;
+        assert_eq!(
+            response(200, &[("link", link)], "[]")
+                .next_link()
+                .as_deref(),
+            Some("https://api.github.com/user/repos?page=3&per_page=100")
+        );
+        let last = r#"<https://api.github.com/user/repos?page=1&per_page=100>; rel="prev", <https://api.github.com/user/repos?page=1&per_page=100>; rel="first""#;
+        assert_eq!(response(200, &[("link", last)], "[]").next_link(), None);
+        assert_eq!(response(200, &[], "[]").next_link(), None);
+    }
+
+    #[test]
+    fn handles_no_content_and_not_modified() {
+        response(204, &[], "").json::<()>().unwrap();
+        assert!(matches!(
+            response(304, &[], "").json::<single::User>().unwrap_err(),
+            Error::NotModified
+        ));
+    }
+}
@@ -0,0 +1,340 @@
+use chrono::Utc;
+use reqwest::{Method, StatusCode};
+use std::time::Duration;
+
+use crate::{transport::Response, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Decides if and when a failed request gets sent again.
+/// Connection errors and `5xx` responses are retried with exponential backoff and jitter, but only for `GET`, `HEAD`, `PUT`, and `DELETE`.
+/// A `POST` or `PATCH` might have been applied before the connection dropped, so sending it again could do it twice (like creating two issues). Use `retry_non_idempotent` to retry them anyway.
+/// Rate limited responses (`403` or `429`) are retried once the `Retry-After` or `X-RateLimit-Reset` header says so, as long as that is not further away than `max_delay`.
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+/// use use_github_api::RetryPolicy;
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(250))
+///     .max_delay(Duration::from_secs(30));
+/// ```
+pub struct RetryPolicy {
+    /// How many times a request is sent at most, including the first time. `1` means that requests are never retried.
+    pub max_attempts: u32,
+    /// The backoff before the second attempt. It doubles for every attempt after that.
+    pub base_delay: Duration,
+    /// The longest that will be waited between two attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize the backoff, so that many clients failing at once do not retry at once.
+    pub jitter: bool,
+    /// Whether `POST` and `PATCH` requests are retried after connection errors and `5xx` responses as well.
+    pub retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Creates the default `RetryPolicy`: 3 attempts, starting at 500ms and never waiting longer than a minute, with jitter, and without retrying `POST` or `PATCH` requests that might have been applied.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// A `RetryPolicy` that never retries.
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Sets the maximum amount of attempts. Values below `1` are treated as `1`.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the second attempt.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the longest wait between two attempts.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Turns jitter on or off.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Turns retrying `POST` and `PATCH` requests after connection errors and `5xx` responses on or off. Off by default.
+    /// Only turn this on if doing the same write twice is harmless.
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// How long to wait before sending the request again, or `None` if it should not be sent again.
+    /// `attempt` is the attempt that just happened, starting at `1`.
+    pub(crate) fn delay(
+        &self,
+        method: &Method,
+        attempt: u32,
+        result: &Result<Response, Error>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        // Rate limited requests were turned away before doing anything, but other failures might have happened after the write went through.
+        let idempotent = matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE
+        );
+        let may_resend = idempotent || self.retry_non_idempotent;
+        let wait = match result {
+            // Only connection problems are worth another try. Errors like an invalid URL or a redirect loop would just happen again.
+            Err(Error::Transport(err)) if may_resend => {
+                match err.downcast_ref::<reqwest::Error>() {
+                    Some(err) if err.is_builder() || err.is_redirect() => return None,
+                    _ => self.backoff(attempt),
+                }
+            }
+            Err(_) => return None,
+            Ok(response) => match response.status {
+                StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+                    if may_resend =>
+                {
+                    response
+                        .retry_after()
+                        .unwrap_or_else(|| self.backoff(attempt))
+                }
+                StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS => {
+                    response.retry_after().or_else(|| {
+                        let rate_limit = response.rate_limit()?;
+                        if rate_limit.remaining > 0 {
+                            return None;
+                        }
+                        // The reset time only has a precision of seconds, so wait one more to be safe.
+                        let until_reset =
+                            (rate_limit.reset - Utc::now()).to_std().unwrap_or_default();
+                        Some(until_reset + Duration::from_secs(1))
+                    })?
+                }
+                _ => return None,
+            },
+        };
+        if wait > self.max_delay {
+            None
+        } else {
+            Some(wait)
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            // "Equal jitter": always wait at least half, then a random amount of the other half.
+            let half = exponential / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            exponential
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Response {
+    /// The `Retry-After` header, in seconds.
+    pub(crate) fn retry_after(&self) -> Option<Duration> {
+        let seconds = self
+            .headers
+            .get("retry-after")?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        Some(Duration::from_secs(seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, headers: &[(&str, String)]) -> Result<Response, Error> {
+        Ok(headers.iter().fold(
+            Response::new(StatusCode::from_u16(status).unwrap(), ""),
+            |response, (name, value)| response.with_header(name, value),
+        ))
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new()
+            .max_attempts(4)
+            .base_delay(Duration::from_millis(100))
+            .jitter(false)
+    }
+
+    #[test]
+    fn backs_off_exponentially_on_server_errors() {
+        let policy = policy();
+        let bad_gateway = response(502, &[]);
+        assert_eq!(
+            policy.delay(&Method::GET, 1, &bad_gateway),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.delay(&Method::GET, 2, &bad_gateway),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.delay(&Method::GET, 3, &bad_gateway),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.delay(&Method::GET, 4, &bad_gateway), None);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let policy = policy()
+            .max_attempts(40)
+            .max_delay(Duration::from_millis(300));
+        assert_eq!(
+            policy.delay(&Method::GET, 30, &response(503, &[])),
+            Some(Duration::from_millis(300))
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = policy().jitter(true);
+        for _ in 0..100 {
+            let delay = policy.delay(&Method::GET, 2, &response(500, &[])).unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn does_not_retry_client_errors() {
+        let policy = policy();
+        assert_eq!(policy.delay(&Method::GET, 1, &response(404, &[])), None);
+        assert_eq!(policy.delay(&Method::GET, 1, &response(422, &[])), None);
+        assert_eq!(policy.delay(&Method::GET, 1, &response(200, &[])), None);
+        // A plain "forbidden", without any rate limit involved.
+        assert_eq!(policy.delay(&Method::GET, 1, &response(403, &[])), None);
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let policy = policy();
+        let secondary = response(403, &[("retry-after", "7".to_owned())]);
+        assert_eq!(
+            policy.delay(&Method::GET, 1, &secondary),
+            Some(Duration::from_secs(7))
+        );
+        let too_long = response(429, &[("retry-after", "120".to_owned())]);
+        assert_eq!(policy.delay(&Method::GET, 1, &too_long), None);
+    }
+
+    #[test]
+    fn honors_rate_limit_reset() {
+        let policy = policy();
+        let reset = Utc::now().timestamp() + 10;
+        let headers = [
+            ("x-ratelimit-limit", "5000".to_owned()),
+            ("x-ratelimit-remaining", "0".to_owned()),
+            ("x-ratelimit-used", "5000".to_owned()),
+            ("x-ratelimit-reset", reset.to_string()),
+        ];
+        let delay = policy
+            .delay(&Method::GET, 1, &response(403, &headers))
+            .unwrap();
+        assert!(delay > Duration::from_secs(9) && delay <= Duration::from_secs(11));
+
+        let far_away = [
+            ("x-ratelimit-limit", "5000".to_owned()),
+            ("x-ratelimit-remaining", "0".to_owned()),
+            ("x-ratelimit-used", "5000".to_owned()),
+            ("x-ratelimit-reset", (reset + 3600).to_string()),
+        ];
+        assert_eq!(
+            policy.delay(&Method::GET, 1, &response(403, &far_away)),
+            None
+        );
+    }
+
+    #[test]
+    fn retries_connection_errors_only() {
+        let policy = policy();
+        let dropped: Result<Response, Error> = Err(Error::Transport("connection reset".into()));
+        assert_eq!(
+            policy.delay(&Method::GET, 1, &dropped),
+            Some(Duration::from_millis(100))
+        );
+        let decode: Result<Response, Error> =
+            Err(serde_json::from_str::<usize>("nope").unwrap_err().into());
+        assert_eq!(policy.delay(&Method::GET, 1, &decode), None);
+    }
+
+    #[test]
+    fn only_retries_idempotent_methods_after_failures() {
+        let policy = policy();
+        let bad_gateway = response(502, &[]);
+        let dropped: Result<Response, Error> = Err(Error::Transport("connection reset".into()));
+        for method in [Method::GET, Method::HEAD, Method::PUT, Method::DELETE] {
+            assert!(policy.delay(&method, 1, &bad_gateway).is_some());
+            assert!(policy.delay(&method, 1, &dropped).is_some());
+        }
+        for method in [Method::POST, Method::PATCH] {
+            assert_eq!(policy.delay(&method, 1, &bad_gateway), None);
+            assert_eq!(policy.delay(&method, 1, &dropped), None);
+        }
+        // Rate limited writes were never applied, so they are still retried.
+        let secondary = response(403, &[("retry-after", "7".to_owned())]);
+        assert_eq!(
+            policy.delay(&Method::POST, 1, &secondary),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn retries_non_idempotent_methods_when_asked() {
+        let policy = policy().retry_non_idempotent(true);
+        assert_eq!(
+            policy.delay(&Method::POST, 1, &response(502, &[])),
+            Some(Duration::from_millis(100))
+        );
+        let dropped: Result<Response, Error> = Err(Error::Transport("connection reset".into()));
+        assert_eq!(
+            policy.delay(&Method::PATCH, 1, &dropped),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn none_never_retries() {
+        assert_eq!(
+            RetryPolicy::none().delay(&Method::GET, 1, &response(502, &[])),
+            None
+        );
+    }
+}
@@ -1,3 +1,2 @@
 #![cfg_attr(not(feature = "auth"), allow(dead_code))]
 pub const FAKE_TOKEN: &str = "ghp_BJKSHKJ3u09uf0";
-pub const BAD_FAKE_TOKEN: &str = "ghp_bad_BJKSHKJ3u09uf0";
@@ -1,4 +1,4 @@
-#[cfg(any(feature = "auth"))]
+#[cfg(feature = "auth")]
 pub mod creation {
     use std::{error::Error as StdError, fmt};
 
@@ -85,62 +85,189 @@ pub mod creation {
     }
 }
 
-pub mod runtime {
+pub mod validation {
     use std::{error::Error as StdError, fmt};
 
-    #[derive(Debug)]
-    pub(crate) enum RuntimeErrorKind {
-        #[cfg(feature = "auth")]
-        BadCredentials,
-        NotFound,
-    }
-
-    #[derive(Debug)]
-    pub struct RuntimeError {
-        pub(crate) kind: RuntimeErrorKind,
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// Errors for parameters that are rejected before a request is sent.
+    pub enum ValidationError {
+        PerPageBiggerThan100,
+        /// A field that GitHub requires to have content, like the title of an issue, is empty.
+        Empty {
+            field: &'static str,
+        },
+        /// A color that is not 6 hex digits, like `f29513`.
+        InvalidColor {
+            color: String,
+        },
     }
 
-    impl RuntimeError {
-        fn new(kind: RuntimeErrorKind) -> Self {
-            Self { kind }
-        }
-
-        #[cfg(feature = "auth")]
-        pub(crate) fn bad_credentials() -> Self {
-            Self::new(RuntimeErrorKind::BadCredentials)
-        }
+    impl StdError for ValidationError {}
 
-        pub(crate) fn not_found() -> Self {
-            Self::new(RuntimeErrorKind::NotFound)
-        }
-    }
-
-    impl StdError for RuntimeError {}
-
-    impl fmt::Display for RuntimeError {
+    impl fmt::Display for ValidationError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            match &self.kind {
-                #[cfg(feature = "auth")]
-                RuntimeErrorKind::BadCredentials => {
-                    write!(f, "Bad credentials")
-                }
-                RuntimeErrorKind::NotFound => {
-                    write!(f, "Either the resource does not exist, or it is protected")
+            match self {
+                ValidationError::PerPageBiggerThan100 => {
+                    write!(
+                        f,
+                        "per_page is bigger than 100. It has to be less than or equivalent to 100."
+                    )
                 }
+                ValidationError::Empty { field } => write!(f, "{} can not be empty.", field),
+                ValidationError::InvalidColor { color } => write!(
+                    f,
+                    "{} is not a valid color. It has to be 6 hex digits, like f29513.",
+                    color
+                ),
             }
         }
     }
     #[cfg(test)]
     mod tests {
-        use crate::RuntimeError;
+        use super::ValidationError;
 
         fn assert_sync<T: Sync>() {}
         fn assert_send<T: Send>() {}
 
         #[test]
         fn test_send_and_sync() {
-            assert_sync::<RuntimeError>();
-            assert_send::<RuntimeError>();
+            assert_sync::<ValidationError>();
+            assert_send::<ValidationError>();
+        }
+    }
+}
+
+use crate::schema::GitHubError;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "auth")]
+use creation::CreationError;
+use reqwest::StatusCode;
+use std::{error::Error as StdError, fmt, time::Duration};
+use validation::ValidationError;
+
+#[derive(Debug)]
+#[non_exhaustive]
+/// Everything that can go wrong when using the library.
+pub enum Error {
+    /// The request could not be sent, or the response could not be read.
+    /// With the default transport, this is a `reqwest::Error`.
+    Transport(Box<dyn StdError + Send + Sync>),
+    /// The response did not have the expected shape.
+    Decode(serde_json::Error),
+    /// An unexpected status, without a GitHub error message in the body.
+    Http { status: StatusCode, body: String },
+    /// The resource has not changed since the `ETag` or date sent along with the request (`304`).
+    NotModified,
+    /// The auth token is invalid or expired (`401`).
+    #[cfg(feature = "auth")]
+    BadCredentials(GitHubError),
+    /// The token is valid, but is not allowed to do this (`403`).
+    Forbidden(GitHubError),
+    /// Either the resource does not exist, or it is protected (`404`).
+    NotFound(GitHubError),
+    /// The request conflicts with the current state of the resource (`409`).
+    Conflict(GitHubError),
+    /// The resource used to exist, but is gone now (`410`).
+    Gone(GitHubError),
+    /// GitHub could not process the request, usually because of invalid fields (`422`). See `GitHubError::errors` for details.
+    Unprocessable(GitHubError),
+    /// The rate limit was hit, and the `RetryPolicy` did not wait it out.
+    /// `retry_after` is set for secondary rate limits, and `reset` for the primary one.
+    RateLimited {
+        error: GitHubError,
+        retry_after: Option<Duration>,
+        reset: Option<DateTime<Utc>>,
+    },
+    /// Something went wrong on GitHub's side (`5xx`), and the `RetryPolicy` did not get past it.
+    /// The body is kept as is, since these are often HTML pages from a proxy.
+    Server { status: StatusCode, body: String },
+    /// Any other error message from GitHub.
+    Api {
+        status: StatusCode,
+        error: GitHubError,
+    },
+    /// The parameters were rejected before sending the request.
+    Validation(ValidationError),
+    /// The client could not be created.
+    #[cfg(feature = "auth")]
+    Creation(CreationError),
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Transport(err) => Some(&**err),
+            Error::Decode(err) => Some(err),
+            Error::Validation(err) => Some(err),
+            #[cfg(feature = "auth")]
+            Error::Creation(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(err) => write!(f, "Error while sending the request: {}", err),
+            Error::Decode(err) => write!(f, "Error while decoding the response: {}", err),
+            Error::Http { status, .. } => write!(f, "GitHub responded with {}", status),
+            Error::NotModified => write!(f, "Not modified"),
+            #[cfg(feature = "auth")]
+            Error::BadCredentials(_) => write!(f, "Bad credentials"),
+            Error::Forbidden(error) => write!(f, "Forbidden: {}", error.message),
+            Error::NotFound(_) => {
+                write!(f, "Either the resource does not exist, or it is protected")
+            }
+            Error::Conflict(error) => write!(f, "Conflict: {}", error.message),
+            Error::Gone(error) => write!(f, "Gone: {}", error.message),
+            Error::Unprocessable(error) => write!(f, "Unprocessable: {}", error.message),
+            Error::Server { status, .. } => write!(f, "GitHub had an error ({})", status),
+            Error::RateLimited { error, .. } => write!(f, "Rate limited: {}", error.message),
+            Error::Api { status, error } => write!(f, "{} ({})", error.message, status),
+            Error::Validation(err) => write!(f, "{}", err),
+            #[cfg(feature = "auth")]
+            Error::Creation(err) => write!(f, "{}", err),
         }
     }
 }
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Transport(Box::new(err))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Decode(err)
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Error::Validation(err)
+    }
+}
+
+#[cfg(feature = "auth")]
+impl From<CreationError> for Error {
+    fn from(err: CreationError) -> Self {
+        Error::Creation(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+
+    fn assert_sync<T: Sync>() {}
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_send_and_sync() {
+        assert_sync::<Error>();
+        assert_send::<Error>();
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    git::Git,
+    schema::{
+        git::{Commit, Mode, NewCommit, NewTree, NewTreeEntry},
+        GitHubError, GitUser,
+    },
+    Error, ValidationError,
+};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Change {
+    Write {
+        path: String,
+        content: Vec<u8>,
+        mode: Mode,
+    },
+    Delete {
+        path: String,
+    },
+}
+
+#[derive(Clone)]
+/// Commits changes to many files at once, without a clone. Made with `Git::commit_builder`.
+///
+/// Every file is uploaded as a blob first. Then a tree is made on top of the tree of the branch, a commit with that tree,
+/// and the branch is fast-forwarded to the commit. If the branch moved on in the meantime, the tree and commit are made again
+/// on top of its new head (the blobs are reused), up to `retries` times.
+/// # Examples
+/// ```rust,no_run
+/// # #[cfg(not(feature = "enterprise"))]
+/// # async fn run() -> Result<(), use_github_api::Error> {
+/// use use_github_api::GithubClient;
+/// let client = GithubClient::new("ghp_akjsdh")?;
+/// let commit = client
+///     .git()
+///     .commit_builder("octocat", "Hello-World", "main", "Rename the crate")?
+///     .write("Cargo.toml", "[package]\nname = \"hello\"\n")
+///     .write("logo.png", vec![0x89, 0x50, 0x4e, 0x47])
+///     .delete("old.txt")
+///     .commit()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct CommitBuilder<'a> {
+    git: Git<'a>,
+    owner: &'a str,
+    repo: &'a str,
+    branch: &'a str,
+    message: String,
+    changes: Vec<Change>,
+    author: Option<GitUser>,
+    committer: Option<GitUser>,
+    retries: usize,
+}
+
+impl<'a> CommitBuilder<'a> {
+    pub(crate) fn new(
+        git: Git<'a>,
+        owner: &'a str,
+        repo: &'a str,
+        branch: &'a str,
+        message: String,
+    ) -> Result<Self, Error> {
+        if message.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "message" }.into());
+        }
+        Ok(Self {
+            git,
+            owner,
+            repo,
+            branch,
+            message,
+            changes: Vec::new(),
+            author: None,
+            committer: None,
+            retries: 3,
+        })
+    }
+
+    /// Creates or replaces a file, with text or bytes.
+    pub fn write(self, path: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+        self.write_with_mode(path, content, Mode::File)
+    }
+
+    /// Creates or replaces a file with a mode, like `Executable` for scripts.
+    /// `write` always uses `File`, so this is also needed to keep an existing file executable.
+    pub fn write_with_mode(
+        mut self,
+        path: impl Into<String>,
+        content: impl Into<Vec<u8>>,
+        mode: Mode,
+    ) -> Self {
+        self.changes.push(Change::Write {
+            path: path.into(),
+            content: content.into(),
+            mode,
+        });
+        self
+    }
+
+    /// Deletes a file. It has to exist on the branch, otherwise creating the tree fails.
+    /// If the branch moves on and the file is gone from it by the retry, the deletion is left out.
+    pub fn delete(mut self, path: impl Into<String>) -> Self {
+        self.changes.push(Change::Delete { path: path.into() });
+        self
+    }
+
+    /// Sets the author. The current authenticated user is used otherwise.
+    pub fn author(mut self, author: GitUser) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the committer. The author is used otherwise.
+    pub fn committer(mut self, committer: GitUser) -> Self {
+        self.committer = Some(committer);
+        self
+    }
+
+    /// Sets how many times the commit is made again when the branch moves on before it can be fast-forwarded. Defaults to 3.
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Makes the commit, and moves the branch to it.
+    /// # Errors
+    /// Will error if any of the requests fails, or with `Error::Unprocessable` if the branch still moved on after every retry.
+    /// The branch is never changed when this errors, but blobs, trees, and commits that were already made stay in the repository (unreachable).
+    pub async fn commit(self) -> Result<Commit, Error> {
+        let (git, owner, repo) = (self.git, self.owner, self.repo);
+        let mut entries = Vec::with_capacity(self.changes.len());
+        for change in &self.changes {
+            entries.push(match change {
+                Change::Write {
+                    path,
+                    content,
+                    mode,
+                } => {
+                    let blob = git.create_blob(owner, repo, content).await?;
+                    NewTreeEntry::blob(path.clone(), blob.sha).mode(*mode)
+                }
+                Change::Delete { path } => NewTreeEntry::delete(path.clone()),
+            });
+        }
+
+        let r#ref = format!("heads/{}", self.branch);
+        let mut retries = self.retries;
+        let mut moved_on = false;
+        loop {
+            let parent = git.get_ref(owner, repo, &r#ref).await?.object.sha;
+            let base = git.get_commit(owner, repo, &parent).await?;
+            let entries = if moved_on {
+                without_missing_deletions(git, owner, repo, &base.tree.sha, &entries).await?
+            } else {
+                entries.clone()
+            };
+            let tree = git
+                .create_tree(owner, repo, &NewTree::new(entries).base_tree(base.tree.sha))
+                .await?;
+            let mut commit = NewCommit::new(self.message.clone(), tree.sha, vec![parent])?;
+            commit.author = self.author.clone();
+            commit.committer = self.committer.clone();
+            let commit = git.create_commit(owner, repo, &commit).await?;
+            match git
+                .update_ref(owner, repo, &r#ref, &commit.sha, false)
+                .await
+            {
+                Ok(_) => return Ok(commit),
+                Err(Error::Unprocessable(error)) if retries > 0 && is_not_fast_forward(&error) => {
+                    retries -= 1;
+                    moved_on = true;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// Whether a `422` from updating the ref means that the branch moved on, which GitHub words as `Update is not a fast forward`.
+/// Other `422`s, like for protected branches or missing required status checks, fail the same way on every retry.
+fn is_not_fast_forward(error: &GitHubError) -> bool {
+    error.message.to_lowercase().contains("not a fast forward")
+}
+
+/// Leaves out the deletions of paths that are not in the tree anymore, since GitHub fails to create a tree that deletes a missing path.
+/// Someone else deleting a file is only possible once the branch has moved on, so this is only needed on retries.
+async fn without_missing_deletions(
+    git: Git<'_>,
+    owner: &str,
+    repo: &str,
+    tree_sha: &str,
+    entries: &[NewTreeEntry],
+) -> Result<Vec<NewTreeEntry>, Error> {
+    let is_deletion = |entry: &NewTreeEntry| entry.sha == Some(None);
+    if !entries.iter().any(is_deletion) {
+        return Ok(entries.to_vec());
+    }
+    let tree = git.get_tree(owner, repo, tree_sha, true).await?;
+    if tree.truncated {
+        return Ok(entries.to_vec());
+    }
+    let paths: HashSet<&str> = tree.tree.iter().map(|entry| entry.path.as_str()).collect();
+    Ok(entries
+        .iter()
+        .filter(|entry| !is_deletion(entry) || paths.contains(entry.path.as_str()))
+        .cloned()
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_utils::{cassette_client, mock_client},
+        transport::{MockTransport, Response},
+        Error,
+    };
+    use reqwest::{Method, StatusCode};
+
+    const COMMIT: &str = r#"{ "sha": "7d1b31e74ee336d15cbd21741bc88a537ed063a0", "node_id": "MDY6Q29tbWl0", "author": { "name": "Mona Octocat", "email": "octocat@github.com" }, "committer": { "name": "Mona Octocat", "email": "octocat@github.com" }, "message": "Initial commit", "tree": { "sha": "9fb037999f264ba9a7fc6274d15fa3ae2ab98312", "url": "" }, "parents": [] }"#;
+
+    #[tokio::test]
+    async fn retries_when_the_branch_moves_on() {
+        let client = cassette_client("git/commit_builder");
+        let commit = client
+            .git()
+            .commit_builder("octocat", "Hello-World", "main", "Bump the version")
+            .unwrap()
+            .write("VERSION", "2.0.0\n")
+            .delete("CHANGELOG.old")
+            .commit()
+            .await
+            .unwrap();
+        assert_eq!(
+            commit.parents[0].sha,
+            "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678"
+        );
+    }
+
+    #[tokio::test]
+    async fn leaves_out_deletions_of_files_gone_after_the_branch_moves_on() {
+        let client = cassette_client("git/commit_builder_deleted");
+        let commit = client
+            .git()
+            .commit_builder("octocat", "Hello-World", "main", "Bump the version")
+            .unwrap()
+            .write("VERSION", "2.0.0\n")
+            .delete("CHANGELOG.old")
+            .commit()
+            .await
+            .unwrap();
+        assert_eq!(
+            commit.parents[0].sha,
+            "a1b2c3d4e5f60718293a4b5c6d7e8f9012345678"
+        );
+    }
+
+    #[tokio::test]
+    async fn gives_up_when_the_branch_keeps_moving_on() {
+        let client = cassette_client("git/commit_builder_out_of_retries");
+        let err = client
+            .git()
+            .commit_builder("octocat", "Hello-World", "main", "Bump the version")
+            .unwrap()
+            .write("VERSION", "2.0.0\n")
+            .delete("CHANGELOG.old")
+            .retries(1)
+            .commit()
+            .await
+            .unwrap_err();
+        match err {
+            Error::Unprocessable(error) => {
+                assert_eq!(error.message, "Update is not a fast forward")
+            }
+            err => panic!("Expected Error::Unprocessable, got {:?}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_other_rejected_updates() {
+        let mock = MockTransport::new();
+        mock.on(
+            Method::POST,
+            "/repos/octocat/Hello-World/git/blobs",
+            Response::new(
+                StatusCode::CREATED,
+                r#"{ "sha": "5c0b6bd9a1f3c4e2d7a8b9c0d1e2f3a4b5c6d7e8", "url": "" }"#,
+            ),
+        );
+        mock.on(
+            Method::GET,
+            "/repos/octocat/Hello-World/git/ref/heads/main",
+            Response::new(StatusCode::OK, r#"{ "ref": "refs/heads/main", "node_id": "MDM6UmVm", "url": "", "object": { "type": "commit", "sha": "7d1b31e74ee336d15cbd21741bc88a537ed063a0", "url": "" } }"#),
+        );
+        mock.on(
+            Method::GET,
+            "/repos/octocat/Hello-World/git/commits/7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+            Response::new(StatusCode::OK, COMMIT),
+        );
+        mock.on(
+            Method::POST,
+            "/repos/octocat/Hello-World/git/trees",
+            Response::new(StatusCode::CREATED, r#"{ "sha": "9fb037999f264ba9a7fc6274d15fa3ae2ab98312", "tree": [], "truncated": false }"#),
+        );
+        mock.on(
+            Method::POST,
+            "/repos/octocat/Hello-World/git/commits",
+            Response::new(StatusCode::CREATED, COMMIT),
+        );
+        mock.on(
+            Method::PATCH,
+            "/repos/octocat/Hello-World/git/refs/heads/main",
+            Response::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                r#"{ "message": "Protected branch update failed for refs/heads/main." }"#,
+            ),
+        );
+        let client = mock_client(&mock);
+        let err = client
+            .git()
+            .commit_builder("octocat", "Hello-World", "main", "Bump the version")
+            .unwrap()
+            .write("VERSION", "2.0.0\n")
+            .delete("CHANGELOG.old")
+            .commit()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Unprocessable(_)));
+        let updates = mock
+            .requests()
+            .iter()
+            .filter(|request| request.method == Method::PATCH)
+            .count();
+        assert_eq!(updates, 1);
+        assert_eq!(mock.requests().len(), 6);
+    }
+}
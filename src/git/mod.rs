@@ -0,0 +1,403 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::{
+    git::{NewCommit, NewTag, NewTree, ShaRef},
+    repos::contents::encode,
+};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::git::{Blob, Commit, Ref, Tag, Tree},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+use serde::Serialize;
+
+#[cfg(any(feature = "auth", doc))]
+mod commit_builder;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub use commit_builder::CommitBuilder;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct NewBlob<'b> {
+    #[serde(serialize_with = "encode")]
+    content: &'b [u8],
+    encoding: &'static str,
+}
+
+#[derive(Serialize)]
+struct Recursive {
+    recursive: u8,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct NewRef<'b> {
+    r#ref: &'b str,
+    sha: &'b str,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct RefUpdate<'b> {
+    sha: &'b str,
+    force: bool,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// The Git database: the blobs, trees, commits, refs, and tags of a repository.
+/// Refs are named without the `refs/` prefix (like `heads/main` or `tags/v1.0`), except when they are created.
+pub struct Git<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Git<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Git { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Starts a commit of changes to many files on `branch`, which is made with blobs, a tree, and a commit, and then fast-forwards the branch.
+    /// # Errors
+    /// Will error if the message is empty.
+    pub fn commit_builder(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        branch: &'a str,
+        message: impl Into<String>,
+    ) -> Result<CommitBuilder<'a>, Error> {
+        CommitBuilder::new(*self, owner, repo, branch, message.into())
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a blob. The content is sent base64 encoded, so it can be any bytes.
+    pub async fn create_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        content: &[u8],
+    ) -> Result<ShaRef, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/git/blobs", owner, repo),
+                    )
+                    .json(&NewBlob {
+                        content,
+                        encoding: "base64",
+                    })?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a blob. Unlike the contents API, this works for files of up to 100 MB.
+    pub async fn get_blob(&self, owner: &str, repo: &str, sha: &str) -> Result<Blob, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/git/blobs/{}", owner, repo, sha),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a tree.
+    pub async fn create_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree: &NewTree,
+    ) -> Result<Tree, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/git/trees", owner, repo),
+                    )
+                    .json(tree)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a tree by its SHA, or by the name of a branch or tag.
+    /// With `recursive`, the entries of every subtree are included too, with their full paths.
+    pub async fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        recursive: bool,
+    ) -> Result<Tree, Error> {
+        let recursive = if recursive {
+            Some(Recursive { recursive: 1 })
+        } else {
+            None
+        };
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/git/trees/{}", owner, repo, tree_sha),
+                    )
+                    .query(&recursive)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a commit. This does not move any branch: use `update_ref` for that.
+    pub async fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &NewCommit,
+    ) -> Result<Commit, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/git/commits", owner, repo),
+                    )
+                    .json(commit)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a commit by its SHA.
+    pub async fn get_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<Commit, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/git/commits/{}", owner, repo, sha),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the refs that start with `prefix`, like `heads/` for branches or `tags/v1` for some tags.
+    /// An empty prefix lists every ref.
+    pub async fn list_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        prefix: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Ref>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/git/matching-refs/{}", owner, repo, prefix),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every ref that starts with `prefix`, page by page.
+    pub fn list_refs_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        prefix: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Ref, Error>> + 'a {
+        let git = *self;
+        paginate_pages(page, limits, move |page| async move {
+            git.list_refs(owner, repo, prefix, Some(page)).await
+        })
+    }
+
+    /// Fetches a ref, like `heads/main`.
+    /// # Errors
+    /// Will error with `Error::NotFound` if the ref does not exist.
+    pub async fn get_ref(&self, owner: &str, repo: &str, r#ref: &str) -> Result<Ref, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/git/ref/{}", owner, repo, r#ref),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a ref that points to `sha`. `r#ref` is the full name, like `refs/heads/feature`.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if the ref already exists.
+    pub async fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        sha: &str,
+    ) -> Result<Ref, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/repos/{}/{}/git/refs", owner, repo))
+                    .json(&NewRef { r#ref, sha })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Points a ref, like `heads/main`, to `sha`. Without `force`, only fast-forwards are allowed.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if `force` is not set and `sha` is not a descendant of where the ref points to.
+    pub async fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        sha: &str,
+        force: bool,
+    ) -> Result<Ref, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/git/refs/{}", owner, repo, r#ref),
+                    )
+                    .json(&RefUpdate { sha, force })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a ref, like `heads/feature`.
+    pub async fn delete_ref(&self, owner: &str, repo: &str, r#ref: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/git/refs/{}", owner, repo, r#ref),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates an annotated tag object. The tag only shows up in the repository once a `refs/tags/...` ref points to it.
+    pub async fn create_tag(&self, owner: &str, repo: &str, tag: &NewTag) -> Result<Tag, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/repos/{}/{}/git/tags", owner, repo))
+                    .json(tag)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches an annotated tag object by its SHA.
+    pub async fn get_tag(&self, owner: &str, repo: &str, sha: &str) -> Result<Tag, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/git/tags/{}", owner, repo, sha),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{schema::git::Mode, test_utils::cassette_client};
+
+    #[tokio::test]
+    async fn trees_can_be_fetched_recursively() {
+        let client = cassette_client("git/tree");
+        let git = client.git();
+        let tree = git
+            .get_tree("octocat", "Hello-World", "main", true)
+            .await
+            .unwrap();
+        assert!(!tree.truncated);
+        let script = tree
+            .tree
+            .iter()
+            .find(|entry| entry.path == "src/build.sh")
+            .unwrap();
+        assert_eq!(script.mode, Mode::Executable);
+        let blob = git
+            .get_blob("octocat", "Hello-World", &script.sha)
+            .await
+            .unwrap();
+        assert_eq!(blob.content, b"#!/bin/sh\ncargo build --release\n");
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn commits_can_be_made_without_a_clone() {
+        use crate::schema::git::{NewCommit, NewTree, NewTreeEntry};
+        let client = cassette_client("git/commit");
+        let git = client.git();
+        let blob = git
+            .create_blob(
+                "octocat",
+                "Hello-World",
+                &[0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0xff],
+            )
+            .await
+            .unwrap();
+        let tree = git
+            .create_tree(
+                "octocat",
+                "Hello-World",
+                &NewTree::new(vec![
+                    NewTreeEntry::blob("logo.png", blob.sha),
+                    NewTreeEntry::file("README.md", "# Hello World\n"),
+                    NewTreeEntry::delete("old.txt"),
+                ])
+                .base_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312"),
+            )
+            .await
+            .unwrap();
+        let commit = git
+            .create_commit(
+                "octocat",
+                "Hello-World",
+                &NewCommit::new(
+                    "Add a logo",
+                    tree.sha,
+                    vec!["7d1b31e74ee336d15cbd21741bc88a537ed063a0".to_owned()],
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let main = git
+            .update_ref("octocat", "Hello-World", "heads/main", &commit.sha, false)
+            .await
+            .unwrap();
+        assert_eq!(main.object.sha, commit.sha);
+    }
+}
@@ -0,0 +1,243 @@
+#[cfg(feature = "auth")]
+use crate::ValidationError;
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::issues::Comment,
+    Error, GithubClient,
+};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Since {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<DateTime<Utc>>,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Body<'b> {
+    body: &'b str,
+}
+
+#[cfg(feature = "auth")]
+impl<'b> Body<'b> {
+    fn new(body: &'b str) -> Result<Self, Error> {
+        if body.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "body" }.into());
+        }
+        Ok(Self { body })
+    }
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Comments on issues. Pull requests share these, for the comments that are not on a line of code.
+pub struct Comments<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Comments<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Comments { client }
+    }
+
+    /// Fetches a page of the comments on an issue, oldest first. `since` only keeps the ones updated at or after a time.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Comment>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/issues/{}/comments", owner, repo, number),
+                    )
+                    .query(&Since { since })?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every comment on an issue, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Comment, Error>> + 'a {
+        let comments = *self;
+        paginate_pages(page, limits, move |page| async move {
+            comments.list(owner, repo, number, since, Some(page)).await
+        })
+    }
+
+    /// Fetches a page of the comments on every issue in a repository, oldest first.
+    pub async fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Comment>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/issues/comments", owner, repo),
+                    )
+                    .query(&Since { since })?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every comment on every issue in a repository, page by page.
+    pub fn list_for_repo_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        since: Option<DateTime<Utc>>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Comment, Error>> + 'a {
+        let comments = *self;
+        paginate_pages(page, limits, move |page| async move {
+            comments.list_for_repo(owner, repo, since, Some(page)).await
+        })
+    }
+
+    /// Fetches a comment by its id.
+    pub async fn get(&self, owner: &str, repo: &str, comment_id: usize) -> Result<Comment, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Comments on an issue.
+    /// # Errors
+    /// Will error if `body` is empty, or if the issue is locked and the current authenticated user is not a collaborator.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        body: &str,
+    ) -> Result<Comment, Error> {
+        let body = Body::new(body)?;
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/issues/{}/comments", owner, repo, number),
+                    )
+                    .json(&body)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces the body of a comment.
+    /// # Errors
+    /// Will error if `body` is empty.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: usize,
+        body: &str,
+    ) -> Result<Comment, Error> {
+        let body = Body::new(body)?;
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id),
+                    )
+                    .json(&body)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a comment.
+    pub async fn delete(&self, owner: &str, repo: &str, comment_id: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/issues/comments/{}", owner, repo, comment_id),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{test_utils::cassette_client, Error, ValidationError};
+
+    #[tokio::test]
+    async fn comment_crud_works() {
+        let client = cassette_client("issues/comments");
+        let comments = client.issues().comments();
+        let comment = comments
+            .create("octocat", "Hello-World", 1347, "Me too")
+            .await
+            .unwrap();
+        assert_eq!(comment.id, 1);
+        let comment = comments
+            .update("octocat", "Hello-World", 1, "Me too, on macOS")
+            .await
+            .unwrap();
+        assert_eq!(comment.body, "Me too, on macOS");
+        let list = comments
+            .list("octocat", "Hello-World", 1347, None, None)
+            .await
+            .unwrap();
+        assert_eq!(list.len(), 1);
+        comments.delete("octocat", "Hello-World", 1).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn empty_bodies_are_not_sent() {
+        let mock = crate::transport::MockTransport::new();
+        let client = crate::test_utils::mock_client(&mock);
+        let err = client
+            .issues()
+            .comments()
+            .create("octocat", "Hello-World", 1347, " ")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationError::Empty { field: "body" })
+        ));
+        assert!(mock.requests().is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::issues::{NewLabel, UpdateLabel};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::issues::Label,
+    transport::encode_segment,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Names<'b> {
+    labels: &'b [&'b str],
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// The labels of a repository, and the ones on its issues. Label names are matched without caring about case.
+pub struct Labels<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Labels<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Labels { client }
+    }
+
+    /// Fetches a page of the labels of a repository.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Label>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/labels", owner, repo))
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every label of a repository, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Label, Error>> + 'a {
+        let labels = *self;
+        paginate_pages(page, limits, move |page| async move {
+            labels.list(owner, repo, Some(page)).await
+        })
+    }
+
+    /// Fetches a label by its name.
+    pub async fn get(&self, owner: &str, repo: &str, name: &str) -> Result<Label, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/labels/{}", owner, repo, encode_segment(name)),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a label.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if a label with the same name already exists.
+    pub async fn create(&self, owner: &str, repo: &str, label: &NewLabel) -> Result<Label, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/repos/{}/{}/labels", owner, repo))
+                    .json(label)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a label, and returns the updated label.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        changes: &UpdateLabel,
+    ) -> Result<Label, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/labels/{}", owner, repo, encode_segment(name)),
+                    )
+                    .json(changes)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a label. It is also removed from every issue that has it.
+    pub async fn delete(&self, owner: &str, repo: &str, name: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/labels/{}", owner, repo, encode_segment(name)),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the labels on an issue.
+    pub async fn list_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Label>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/issues/{}/labels", owner, repo, number),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds labels to an issue, and returns every label that is on it now.
+    /// Labels that do not exist in the repository yet are created.
+    pub async fn add_to_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        names: &[&str],
+    ) -> Result<Vec<Label>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/issues/{}/labels", owner, repo, number),
+                    )
+                    .json(&Names { labels: names })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces all of the labels on an issue. An empty slice removes all of them.
+    pub async fn set_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        names: &[&str],
+    ) -> Result<Vec<Label>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/repos/{}/{}/issues/{}/labels", owner, repo, number),
+                    )
+                    .json(&Names { labels: names })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a label from an issue, and returns the labels that are left.
+    /// # Errors
+    /// Will error with `Error::NotFound` if the issue does not have the label.
+    pub async fn remove_from_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        name: &str,
+    ) -> Result<Vec<Label>, Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!(
+                    "/repos/{}/{}/issues/{}/labels/{}",
+                    owner,
+                    repo,
+                    number,
+                    encode_segment(name)
+                ),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes every label from an issue.
+    pub async fn remove_all_from_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+    ) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/issues/{}/labels", owner, repo, number),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::issues::{NewLabel, UpdateLabel},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn label_management_works() {
+        let client = cassette_client("issues/labels");
+        let labels = client.issues().labels();
+        let label = labels
+            .create(
+                "octocat",
+                "Hello-World",
+                &NewLabel::new("good first issue", "#7057ff").unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(label.color, "7057ff");
+        let label = labels
+            .update(
+                "octocat",
+                "Hello-World",
+                "good first issue",
+                &UpdateLabel::new()
+                    .description("Good for newcomers")
+                    .color("008672")
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(label.description.as_deref(), Some("Good for newcomers"));
+        let on_issue = labels
+            .add_to_issue("octocat", "Hello-World", 1347, &["good first issue"])
+            .await
+            .unwrap();
+        assert_eq!(on_issue.len(), 2);
+        let on_issue = labels
+            .set_for_issue("octocat", "Hello-World", 1347, &["bug"])
+            .await
+            .unwrap();
+        assert_eq!(on_issue.len(), 1);
+        let on_issue = labels
+            .remove_from_issue("octocat", "Hello-World", 1347, "bug")
+            .await
+            .unwrap();
+        assert!(on_issue.is_empty());
+        labels
+            .delete("octocat", "Hello-World", "good first issue")
+            .await
+            .unwrap();
+    }
+}
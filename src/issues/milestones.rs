@@ -0,0 +1,171 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::issues::{NewMilestone, UpdateMilestone};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::issues::{Milestone, MilestoneListParams},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// The milestones of a repository. They are referred to by their number, not their id.
+pub struct Milestones<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Milestones<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Milestones { client }
+    }
+
+    /// Fetches a page of the milestones of a repository.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: Option<MilestoneListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Milestone>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/milestones", owner, repo),
+                    )
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every milestone of a repository that matches `params`, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        params: Option<MilestoneListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Milestone, Error>> + 'a {
+        let milestones = *self;
+        paginate_pages(page, limits, move |page| async move {
+            milestones.list(owner, repo, params, Some(page)).await
+        })
+    }
+
+    /// Fetches a milestone by its number.
+    pub async fn get(&self, owner: &str, repo: &str, number: usize) -> Result<Milestone, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/milestones/{}", owner, repo, number),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a milestone.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if a milestone with the same title already exists.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &NewMilestone,
+    ) -> Result<Milestone, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/milestones", owner, repo),
+                    )
+                    .json(milestone)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a milestone, and returns the updated milestone.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        changes: &UpdateMilestone,
+    ) -> Result<Milestone, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/milestones/{}", owner, repo, number),
+                    )
+                    .json(changes)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a milestone. Its issues are kept, but lose the milestone.
+    pub async fn delete(&self, owner: &str, repo: &str, number: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/milestones/{}", owner, repo, number),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::issues::{NewMilestone, State, UpdateMilestone},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn milestone_management_works() {
+        let client = cassette_client("issues/milestones");
+        let milestones = client.issues().milestones();
+        let milestone = milestones
+            .create(
+                "octocat",
+                "Hello-World",
+                &NewMilestone::new("v1.0")
+                    .unwrap()
+                    .description("Tracking milestone for version 1.0"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(milestone.number, 1);
+        let milestone = milestones
+            .update(
+                "octocat",
+                "Hello-World",
+                1,
+                &UpdateMilestone::new().state(State::Closed),
+            )
+            .await
+            .unwrap();
+        assert_eq!(milestone.state, State::Closed);
+        assert!(milestone.closed_at.is_some());
+        milestones
+            .delete("octocat", "Hello-World", 1)
+            .await
+            .unwrap();
+    }
+}
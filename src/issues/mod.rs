@@ -0,0 +1,328 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::issues::{LockReason, NewIssue, UpdateIssue};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::issues::{Issue, ListParams},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+pub mod comments;
+pub mod labels;
+pub mod milestones;
+use comments::Comments;
+use labels::Labels;
+use milestones::Milestones;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Lock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_reason: Option<LockReason>,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Issues<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Issues<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Issues { client }
+    }
+
+    /// Comments on issues and pull requests.
+    pub fn comments(&self) -> Comments<'a> {
+        Comments::new(self.client)
+    }
+
+    /// Labels of repositories and issues.
+    pub fn labels(&self) -> Labels<'a> {
+        Labels::new(self.client)
+    }
+
+    /// Milestones of repositories.
+    pub fn milestones(&self) -> Milestones<'a> {
+        Milestones::new(self.client)
+    }
+
+    /// Fetches a page of the issues (and pull requests) in a repository.
+    pub async fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Issue>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/issues", owner, repo))
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every issue (and pull request) in a repository that matches `params`, page by page.
+    pub fn list_for_repo_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Issue, Error>> + 'a {
+        let issues = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move { issues.list_for_repo(owner, repo, params, Some(page)).await }
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the issues in an organization that the current authenticated user is involved with (see `Filter`).
+    pub async fn list_for_org(
+        &self,
+        org: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Issue>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/issues", org))
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every issue in an organization that matches `params`, page by page.
+    pub fn list_for_org_stream(
+        &self,
+        org: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Issue, Error>> + 'a {
+        let issues = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move { issues.list_for_org(org, params, Some(page)).await }
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the issues in the current authenticated user's own repositories, and the ones they are involved with (see `Filter`).
+    pub async fn list_for_user(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Issue>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/issues")
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every issue of the current authenticated user that matches `params`, page by page.
+    pub fn list_for_user_stream(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Issue, Error>> + 'a {
+        let issues = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move { issues.list_for_user(params, Some(page)).await }
+        })
+    }
+
+    /// Fetches an issue (or pull request) by its number.
+    /// # Errors
+    /// Will error if the issue does not exist, and with `Error::Gone` if it was deleted.
+    pub async fn get(&self, owner: &str, repo: &str, number: usize) -> Result<Issue, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/issues/{}", owner, repo, number),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates an issue.
+    /// # Errors
+    /// Will error if issues are turned off for the repository. Assignees, labels, and the milestone are silently dropped if the current authenticated user cannot push to the repository.
+    pub async fn create(&self, owner: &str, repo: &str, issue: &NewIssue) -> Result<Issue, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/repos/{}/{}/issues", owner, repo))
+                    .json(issue)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes an issue, and returns the updated issue.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        changes: &UpdateIssue,
+    ) -> Result<Issue, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/issues/{}", owner, repo, number),
+                    )
+                    .json(changes)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Locks the conversation of an issue, so that only collaborators can comment.
+    pub async fn lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        reason: Option<LockReason>,
+    ) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/repos/{}/{}/issues/{}/lock", owner, repo, number),
+                    )
+                    .json(&Lock {
+                        lock_reason: reason,
+                    })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Unlocks the conversation of an issue.
+    pub async fn unlock(&self, owner: &str, repo: &str, number: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/issues/{}/lock", owner, repo, number),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pagination::{Limits, PageParams},
+        schema::issues::{Issue, ListParams, NewIssue, StateFilter},
+        test_utils::cassette_client,
+        Error, ValidationError,
+    };
+
+    #[tokio::test]
+    async fn list_for_repo_stream_sends_the_filters() {
+        use futures::TryStreamExt;
+        let client = cassette_client("issues/list");
+        let params = ListParams::new()
+            .state(StateFilter::All)
+            .labels(&["bug", "needs triage"]);
+        let page = PageParams::new(Some(2), None).unwrap();
+        let data: Vec<Issue> = client
+            .issues()
+            .list_for_repo_stream(
+                "octocat",
+                "Hello-World",
+                Some(params),
+                Some(page),
+                Limits::new(),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 3);
+        assert!(!data[0].is_pull_request());
+        assert!(data[2].is_pull_request());
+        assert_eq!(data[1].labels[0].name, "bug");
+    }
+
+    #[test]
+    fn new_issue_needs_a_title() {
+        assert!(matches!(
+            NewIssue::new("  "),
+            Err(Error::Validation(ValidationError::Empty { field: "title" }))
+        ));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn create_update_and_lock_work() {
+        use crate::schema::issues::{LockReason, State, UpdateIssue};
+        let client = cassette_client("issues/manage");
+        let issues = client.issues();
+        let issue = issues
+            .create(
+                "octocat",
+                "Hello-World",
+                &NewIssue::new("Found a bug")
+                    .unwrap()
+                    .body("I'm having a problem with this.")
+                    .labels(&["bug"]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(issue.number, 1347);
+        let issue = issues
+            .update(
+                "octocat",
+                "Hello-World",
+                1347,
+                &UpdateIssue::new().state(State::Closed),
+            )
+            .await
+            .unwrap();
+        assert_eq!(issue.state, State::Closed);
+        issues
+            .lock("octocat", "Hello-World", 1347, Some(LockReason::Resolved))
+            .await
+            .unwrap();
+        issues.unlock("octocat", "Hello-World", 1347).await.unwrap();
+    }
+}
@@ -1,17 +1,29 @@
 #![warn(rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+pub mod checks;
 pub mod client;
 mod error;
+pub mod git;
+pub mod issues;
+pub mod orgs;
+pub mod pagination;
+pub mod pulls;
+pub mod rate_limit;
+pub mod repos;
 pub mod schema;
+pub mod teams;
+pub mod transport;
 pub mod users;
-pub use client::GithubClient;
 #[cfg(feature = "auth")]
 pub use client::GithubClientBuilder;
+pub use client::{GithubClient, RetryPolicy};
 #[cfg(feature = "auth")]
 pub use error::creation::CreationError;
-pub use error::runtime::RuntimeError;
+pub use error::{validation::ValidationError, Error};
 #[cfg(test)]
 pub(crate) mod constants;
 #[cfg(test)]
 pub(crate) mod test_utils;
-pub(crate) mod utils;
@@ -0,0 +1,97 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::users::list,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Blocking and unblocking users for organizations. Only owners can do this. Requires auth.
+pub struct Blocks<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Blocks<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Blocks { client }
+    }
+
+    /// Fetches a page of the users that an organization has blocked.
+    pub async fn list(
+        &self,
+        org: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/blocks", org))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every user that an organization has blocked, page by page.
+    pub fn list_stream(
+        &self,
+        org: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::User, Error>> + 'a {
+        let blocks = *self;
+        paginate_pages(params, limits, move |params| async move {
+            blocks.list(org, Some(params)).await
+        })
+    }
+
+    /// Checks if an organization has blocked a user.
+    pub async fn is_blocked(&self, org: &str, username: &str) -> Result<bool, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/blocks/{}", org, username)),
+            )
+            .await?
+            .exists()
+    }
+
+    /// Blocks a user from an organization.
+    pub async fn block(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PUT, &format!("/orgs/{}/blocks/{}", org, username)),
+            )
+            .await?
+            .json()
+    }
+
+    /// Unblocks a user from an organization.
+    pub async fn unblock(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/blocks/{}", org, username),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn block_and_unblock_work() {
+        let client = cassette_client("orgs/blocks");
+        let blocks = client.orgs().blocks();
+        blocks.block("github", "spammer").await.unwrap();
+        assert!(blocks.is_blocked("github", "spammer").await.unwrap());
+        blocks.unblock("github", "spammer").await.unwrap();
+        assert!(!blocks.is_blocked("github", "spammer").await.unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::orgs::invitations::{Invitation, NewInvitation},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Invitations to join organizations. Only owners can see and manage them. Requires auth.
+pub struct Invitations<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Invitations<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Invitations { client }
+    }
+
+    /// Fetches a page of the pending invitations of an organization.
+    pub async fn list(
+        &self,
+        org: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<Invitation>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/invitations", org))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every pending invitation of an organization, page by page.
+    pub fn list_stream(
+        &self,
+        org: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Invitation, Error>> + 'a {
+        let invitations = *self;
+        paginate_pages(params, limits, move |params| async move {
+            invitations.list(org, Some(params)).await
+        })
+    }
+
+    /// Invites a user, or an email address, to an organization.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if the user is already a member, or has already been invited.
+    pub async fn create(&self, org: &str, invitation: &NewInvitation) -> Result<Invitation, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/orgs/{}/invitations", org))
+                    .json(invitation)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Cancels an invitation.
+    pub async fn cancel(&self, org: &str, invitation_id: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/invitations/{}", org, invitation_id),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        schema::orgs::invitations::{NewInvitation, NewRole, Role},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn invitations_can_be_cancelled() {
+        let client = cassette_client("orgs/invitations");
+        let invitations = client.orgs().invitations();
+        let invitation = invitations
+            .create(
+                "github",
+                &NewInvitation::email("octocat@github.com").role(NewRole::Admin),
+            )
+            .await
+            .unwrap();
+        assert_eq!(invitation.login, None);
+        assert_eq!(invitation.role, Role::Admin);
+        assert_eq!(invitations.list("github", None).await.unwrap().len(), 1);
+        invitations.cancel("github", invitation.id).await.unwrap();
+    }
+}
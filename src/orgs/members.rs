@@ -0,0 +1,258 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::orgs::members::{Membership, NewRole};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::{
+        orgs::members::{Filter, ListParams},
+        users::list::User,
+    },
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct SetRole {
+    role: NewRole,
+}
+
+#[derive(Serialize)]
+struct FilterParams {
+    filter: Filter,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Members and outside collaborators of organizations.
+/// Without auth (or for non-members), only the public members are shown.
+pub struct Members<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Members<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Members { client }
+    }
+
+    /// Fetches a page of the members of an organization.
+    pub async fn list(
+        &self,
+        org: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/members", org))
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every member of an organization that matches `params`, page by page.
+    pub fn list_stream(
+        &self,
+        org: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<User, Error>> + 'a {
+        let members = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move { members.list(org, params, Some(page)).await }
+        })
+    }
+
+    /// Checks if a user is a member of an organization. For non-members, this is only `true` for public members.
+    pub async fn is_member(&self, org: &str, username: &str) -> Result<bool, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/members/{}", org, username)),
+            )
+            .await?
+            .exists()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a user from an organization, with their access to its repositories. Only owners can do this.
+    pub async fn remove(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/members/{}", org, username),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches the membership of a user in an organization, which is pending until they accept the invitation.
+    /// # Errors
+    /// Will error with `Error::NotFound` if the user is not a member and has not been invited.
+    pub async fn membership(&self, org: &str, username: &str) -> Result<Membership, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/orgs/{}/memberships/{}", org, username),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Sets the role of a member, or invites a user with a role. Only owners can do this.
+    pub async fn set_membership(
+        &self,
+        org: &str,
+        username: &str,
+        role: NewRole,
+    ) -> Result<Membership, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/orgs/{}/memberships/{}", org, username),
+                    )
+                    .json(&SetRole { role })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a member, or cancels the invitation of a user. Only owners can do this.
+    pub async fn remove_membership(&self, org: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/memberships/{}", org, username),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the outside collaborators of an organization: users with access to some of its repositories, who are not members.
+    pub async fn outside_collaborators(
+        &self,
+        org: &str,
+        filter: Option<Filter>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/outside_collaborators", org))
+                    .query(&filter.map(|filter| FilterParams { filter }))?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every outside collaborator of an organization, page by page.
+    pub fn outside_collaborators_stream(
+        &self,
+        org: &'a str,
+        filter: Option<Filter>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<User, Error>> + 'a {
+        let members = *self;
+        paginate_pages(page, limits, move |page| async move {
+            members.outside_collaborators(org, filter, Some(page)).await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Turns a member into an outside collaborator, who keeps access to the repositories that they were given access to directly.
+    pub async fn convert_to_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::PUT,
+                &format!("/orgs/{}/outside_collaborators/{}", org, username),
+            ))
+            .await?
+            .json::<serde_json::Value>()
+            .map(|_| ())
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes an outside collaborator from every repository of an organization.
+    pub async fn remove_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/outside_collaborators/{}", org, username),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        pagination::Limits,
+        schema::orgs::members::{ListParams, RoleFilter},
+        test_utils::cassette_client,
+    };
+    use futures::TryStreamExt;
+
+    #[tokio::test]
+    async fn list_stream_filters_by_role() {
+        let client = cassette_client("orgs/members_list");
+        let admins: Vec<_> = client
+            .orgs()
+            .members()
+            .list_stream(
+                "github",
+                Some(ListParams::new().role(RoleFilter::Admin)),
+                None,
+                Limits::new(),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(admins.len(), 1);
+        assert_eq!(admins[0].login, "octocat");
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn members_can_be_offboarded() {
+        use crate::schema::orgs::members::{NewRole, Role, State};
+        let client = cassette_client("orgs/members");
+        let members = client.orgs().members();
+        let membership = members
+            .set_membership("github", "hubot", NewRole::Member)
+            .await
+            .unwrap();
+        assert_eq!(membership.state, State::Pending);
+        assert_eq!(membership.role, Role::Member);
+        assert!(members.is_member("github", "hubot").await.unwrap());
+        members.remove("github", "hubot").await.unwrap();
+        assert!(!members.is_member("github", "hubot").await.unwrap());
+    }
+}
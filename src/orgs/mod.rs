@@ -0,0 +1,174 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::orgs::{list, single},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod blocks;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod invitations;
+pub mod members;
+#[cfg(any(feature = "auth", doc))]
+use blocks::Blocks;
+#[cfg(any(feature = "auth", doc))]
+use invitations::Invitations;
+use members::Members;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Orgs<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Orgs<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Orgs { client }
+    }
+
+    /// Members and outside collaborators of organizations.
+    pub fn members(&self) -> Members<'a> {
+        Members::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Invitations to join organizations.
+    pub fn invitations(&self) -> Invitations<'a> {
+        Invitations::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The users blocked by organizations.
+    pub fn blocks(&self) -> Blocks<'a> {
+        Blocks::new(self.client)
+    }
+
+    /// Fetches an organization.
+    /// Members see a few more fields, and owners a few more than that.
+    /// # Errors
+    /// Will error if the organization does not exist.
+    pub async fn get(&self, org: &str) -> Result<single::Organization, Error> {
+        self.client
+            .send(self.client.request(Method::GET, &format!("/orgs/{}", org)))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Updates an organization, and returns the updated organization. Only owners can do this.
+    pub async fn update(
+        &self,
+        org: &str,
+        params: single::UpdateParams,
+    ) -> Result<single::Organization, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PATCH, &format!("/orgs/{}", org))
+                    .json(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the organizations that a user is a public member of.
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::Organization>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/orgs", username))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every organization that a user is a public member of, page by page.
+    pub fn list_for_user_stream(
+        &self,
+        username: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::Organization, Error>> + 'a {
+        let orgs = *self;
+        paginate_pages(params, limits, move |params| async move {
+            orgs.list_for_user(username, Some(params)).await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the organizations that the current authenticated user is a member of, public or not.
+    pub async fn list_for_current(
+        &self,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::Organization>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/orgs")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every organization that the current authenticated user is a member of, page by page.
+    pub fn list_for_current_stream(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::Organization, Error>> + 'a {
+        let orgs = *self;
+        paginate_pages(params, limits, move |params| async move {
+            orgs.list_for_current(Some(params)).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn get_works() {
+        let client = cassette_client("orgs/get");
+        let org = client.orgs().get("github").await.unwrap();
+        assert_eq!(org.login, "github");
+        assert_eq!(org.plan.unwrap().seats, Some(10));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn update_works() {
+        use crate::schema::{
+            orgs::single::{DefaultPermission, UpdateParams},
+            repos::Permission,
+        };
+        let client = cassette_client("orgs/update");
+        let org = client
+            .orgs()
+            .update(
+                "github",
+                UpdateParams::new()
+                    .description("Making the world a better place")
+                    .default_repository_permission(DefaultPermission::Read),
+            )
+            .await
+            .unwrap();
+        assert_eq!(org.default_repository_permission, Some(Permission::Read));
+    }
+}
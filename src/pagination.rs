@@ -0,0 +1,237 @@
+use futures::{stream, Stream, StreamExt};
+use serde::Serialize;
+use std::{cell::RefCell, future::Future};
+
+use crate::{Error, ValidationError};
+
+tokio::task_local! {
+    /// The URL of the `next` link of the latest response, while a page is being fetched.
+    static NEXT_LINK: RefCell<Option<String>>;
+}
+
+/// Remembers the `next` link of a response, if a page is being fetched right now.
+pub(crate) fn set_next_link(link: Option<String>) {
+    let _ = NEXT_LINK.try_with(|next| *next.borrow_mut() = link);
+}
+
+/// The value of a parameter in the query of a URL.
+pub(crate) fn query_param(url: &str, name: &str) -> Option<String> {
+    let (_, query) = url.split_once('?')?;
+    serde_urlencoded::from_str::<Vec<(String, String)>>(query)
+        .ok()?
+        .into_iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Caps for how far a paginated stream will walk.
+/// By default there are no caps, so the stream only ends once the API runs out of items.
+/// # Examples
+/// ```rust
+/// use use_github_api::pagination::Limits;
+/// let limits = Limits::new().max_pages(10).max_items(250);
+/// ```
+pub struct Limits {
+    pub max_pages: Option<usize>,
+    pub max_items: Option<usize>,
+}
+
+impl Limits {
+    /// Creates a new `Limits` without any caps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum amount of pages (requests) that will be fetched.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Sets the maximum amount of items that will be yielded.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+/// `per_page` and `page`, for endpoints that are paginated by page number.
+pub struct PageParams {
+    pub per_page: Option<usize>,
+    pub page: Option<usize>,
+}
+
+impl PageParams {
+    /// # Errors
+    /// Will error if `per_page` is bigger than 100.
+    pub fn new(per_page: Option<usize>, page: Option<usize>) -> Result<Self, Error> {
+        if let Some(per_page) = per_page {
+            if per_page > 100 {
+                return Err(ValidationError::PerPageBiggerThan100.into());
+            }
+        }
+        Ok(Self { per_page, page })
+    }
+}
+
+/// Walks an endpoint that is paginated by page number, starting at the page in `params` (or the first one).
+/// The page to fetch next comes from the `Link` header, so the walk stops right after the last page.
+pub(crate) fn paginate_pages<'a, T, F, Fut>(
+    params: Option<PageParams>,
+    limits: Limits,
+    fetch: F,
+) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    F: FnMut(PageParams) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>, Error>> + 'a,
+{
+    let params = params.unwrap_or_default();
+    let start = PageParams {
+        page: Some(params.page.unwrap_or(1)),
+        ..params
+    };
+    paginate(start, limits, fetch, move |link| {
+        Some(PageParams {
+            page: Some(query_param(link, "page")?.parse().ok()?),
+            ..params
+        })
+    })
+}
+
+/// Walks a paginated endpoint by following the `Link: rel="next"` header of every page.
+/// `fetch` gets the cursor for a page and returns the items on that page. `next` makes the cursor for the next page out of the URL of the `next` link.
+/// The stream ends when a page has no `next` link, when a page is empty, after the first error, or when one of the `limits` is hit.
+pub(crate) fn paginate<'a, T, C, F, Fut, N>(
+    start: C,
+    limits: Limits,
+    fetch: F,
+    next: N,
+) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    C: 'a,
+    F: FnMut(C) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>, Error>> + 'a,
+    N: Fn(&str) -> Option<C> + 'a,
+{
+    let max_pages = limits.max_pages.unwrap_or(usize::MAX);
+    let pages = stream::unfold(
+        (Some(start), 0, fetch, next),
+        move |(cursor, fetched, mut fetch, next)| async move {
+            if fetched >= max_pages {
+                return None;
+            }
+            let page = fetch(cursor?);
+            let (page, link) = NEXT_LINK
+                .scope(RefCell::new(None), async {
+                    let page = page.await;
+                    (page, NEXT_LINK.with(|link| link.take()))
+                })
+                .await;
+            match page {
+                Ok(items) if items.is_empty() => None,
+                Ok(items) => {
+                    let cursor = link.as_deref().and_then(&next);
+                    Some((Ok(items), (cursor, fetched + 1, fetch, next)))
+                }
+                Err(err) => Some((Err(err), (None, fetched + 1, fetch, next))),
+            }
+        },
+    );
+    pages
+        .flat_map(|page| {
+            stream::iter(match page {
+                Ok(items) => items.into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            })
+        })
+        .take(limits.max_items.unwrap_or(usize::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, TryStreamExt};
+
+    // Pages of 3, counting up from the cursor, until 10. Every page but the last links to the next one, like GitHub does.
+    async fn fetch(start: usize) -> Result<Vec<usize>, Error> {
+        let items: Vec<usize> = (start..10).take(3).collect();
+        if start + 3 < 10 {
+            set_next_link(Some(format!(
+                "https://api.github.com/items?start={}",
+                start + 3
+            )));
+        }
+        Ok(items)
+    }
+
+    fn next(link: &str) -> Option<usize> {
+        query_param(link, "start")?.parse().ok()
+    }
+
+    #[test]
+    fn walks_every_page() {
+        let items: Vec<usize> =
+            block_on(paginate(0, Limits::new(), fetch, next).try_collect()).unwrap();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn stops_at_max_pages() {
+        let items: Vec<usize> =
+            block_on(paginate(0, Limits::new().max_pages(2), fetch, next).try_collect()).unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn pages_follow_the_link_header() {
+        let fetched = RefCell::new(Vec::new());
+        let items: Vec<usize> = block_on(
+            paginate_pages(
+                Some(PageParams::new(Some(3), None).unwrap()),
+                Limits::new(),
+                |params| {
+                    fetched.borrow_mut().push(params);
+                    async move {
+                        let page = params.page.unwrap();
+                        // A short page in the middle, which does not mean that it is the last one.
+                        let items = if page == 2 { vec![2] } else { vec![page; 3] };
+                        if page < 3 {
+                            set_next_link(Some(format!(
+                                "https://api.github.com/items?per_page=3&page={}",
+                                page + 1
+                            )));
+                        }
+                        Ok(items)
+                    }
+                },
+            )
+            .try_collect(),
+        )
+        .unwrap();
+        assert_eq!(items, vec![1, 1, 1, 2, 3, 3, 3]);
+        // No request is spent on an empty page after the last one.
+        let fetched = fetched.into_inner();
+        assert_eq!(fetched.len(), 3);
+        assert_eq!(fetched[2], PageParams::new(Some(3), Some(3)).unwrap());
+    }
+
+    #[test]
+    fn stops_at_max_items() {
+        let items: Vec<usize> =
+            block_on(paginate(0, Limits::new().max_items(4), fetch, next).try_collect()).unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn reads_query_params() {
+        let link = "https://api.github.com/users?since=46&per_page=30";
+        assert_eq!(query_param(link, "since").as_deref(), Some("46"));
+        assert_eq!(query_param(link, "page"), None);
+    }
+}
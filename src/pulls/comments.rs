@@ -0,0 +1,261 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::pulls::NewReviewComment;
+#[cfg(feature = "auth")]
+use crate::ValidationError;
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::pulls::ReviewComment,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Body<'b> {
+    body: &'b str,
+}
+
+#[cfg(feature = "auth")]
+impl<'b> Body<'b> {
+    fn new(body: &'b str) -> Result<Self, Error> {
+        if body.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "body" }.into());
+        }
+        Ok(Self { body })
+    }
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Review comments: comments on lines of the diff of a pull request.
+pub struct Comments<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Comments<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Comments { client }
+    }
+
+    /// Fetches a page of the review comments on a pull request, oldest first.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<ReviewComment>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/pulls/{}/comments", owner, repo, number),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every review comment on a pull request, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<ReviewComment, Error>> + 'a {
+        let comments = *self;
+        paginate_pages(page, limits, move |page| async move {
+            comments.list(owner, repo, number, Some(page)).await
+        })
+    }
+
+    /// Fetches a page of the review comments on every pull request in a repository.
+    pub async fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<ReviewComment>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/pulls/comments", owner, repo),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every review comment on every pull request in a repository, page by page.
+    pub fn list_for_repo_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<ReviewComment, Error>> + 'a {
+        let comments = *self;
+        paginate_pages(page, limits, move |page| async move {
+            comments.list_for_repo(owner, repo, Some(page)).await
+        })
+    }
+
+    /// Fetches a review comment by its id.
+    pub async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: usize,
+    ) -> Result<ReviewComment, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/pulls/comments/{}", owner, repo, comment_id),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Comments on a line of the diff, outside of a review.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        comment: &NewReviewComment,
+    ) -> Result<ReviewComment, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/pulls/{}/comments", owner, repo, number),
+                    )
+                    .json(comment)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replies to a review comment. Replies to replies are not supported by GitHub, so `comment_id` has to be a top level comment.
+    /// # Errors
+    /// Will error if `body` is empty.
+    pub async fn reply(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        comment_id: usize,
+        body: &str,
+    ) -> Result<ReviewComment, Error> {
+        let body = Body::new(body)?;
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/comments/{}/replies",
+                            owner, repo, number, comment_id
+                        ),
+                    )
+                    .json(&body)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces the body of a review comment.
+    /// # Errors
+    /// Will error if `body` is empty.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: usize,
+        body: &str,
+    ) -> Result<ReviewComment, Error> {
+        let body = Body::new(body)?;
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/pulls/comments/{}", owner, repo, comment_id),
+                    )
+                    .json(&body)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a review comment.
+    pub async fn delete(&self, owner: &str, repo: &str, comment_id: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/pulls/comments/{}", owner, repo, comment_id),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::pulls::{NewReviewComment, Side},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn comments_and_replies_work() {
+        let client = cassette_client("pulls/comments");
+        let comments = client.pulls().comments();
+        let comment = comments
+            .create(
+                "octocat",
+                "Hello-World",
+                1347,
+                &NewReviewComment::new(
+                    "Great stuff!",
+                    "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "file1.txt",
+                    2,
+                )
+                .unwrap()
+                .side(Side::Right),
+            )
+            .await
+            .unwrap();
+        assert_eq!(comment.line, Some(2));
+        let reply = comments
+            .reply("octocat", "Hello-World", 1347, comment.id, "Thanks!")
+            .await
+            .unwrap();
+        assert_eq!(reply.in_reply_to_id, Some(comment.id));
+        comments
+            .delete("octocat", "Hello-World", reply.id)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,379 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::pulls::{MergeParams, MergeResult, NewPull, UpdatePull};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::pulls::{Commit, File, ListParams, PullRequest},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+pub mod comments;
+pub mod reviews;
+use comments::Comments;
+use reviews::Reviews;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Reviewers<'b> {
+    reviewers: &'b [&'b str],
+    team_reviewers: &'b [&'b str],
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Pulls<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Pulls<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Pulls { client }
+    }
+
+    /// Reviews of pull requests.
+    pub fn reviews(&self) -> Reviews<'a> {
+        Reviews::new(self.client)
+    }
+
+    /// Comments on lines of the diffs of pull requests. Comments on the pull request as a whole are issue comments.
+    pub fn comments(&self) -> Comments<'a> {
+        Comments::new(self.client)
+    }
+
+    /// Fetches a page of the pull requests in a repository.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<PullRequest>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/pulls", owner, repo))
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every pull request in a repository that matches `params`, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<PullRequest, Error>> + 'a {
+        let pulls = *self;
+        paginate_pages(page, limits, move |page| {
+            let params = params.clone();
+            async move { pulls.list(owner, repo, params, Some(page)).await }
+        })
+    }
+
+    /// Fetches a pull request by its number, with whether it can be merged.
+    pub async fn get(&self, owner: &str, repo: &str, number: usize) -> Result<PullRequest, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/pulls/{}", owner, repo, number),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a pull request.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if there is already a pull request from `head` into `base`, or if there are no changes.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull: &NewPull,
+    ) -> Result<PullRequest, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/repos/{}/{}/pulls", owner, repo))
+                    .json(pull)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a pull request, and returns the updated pull request.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        changes: &UpdatePull,
+    ) -> Result<PullRequest, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!("/repos/{}/{}/pulls/{}", owner, repo, number),
+                    )
+                    .json(changes)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the commits on a pull request, oldest first. Only the first 250 commits can be listed.
+    pub async fn commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Commit>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/pulls/{}/commits", owner, repo, number),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every commit on a pull request, page by page.
+    pub fn commits_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Commit, Error>> + 'a {
+        let pulls = *self;
+        paginate_pages(page, limits, move |page| async move {
+            pulls.commits(owner, repo, number, Some(page)).await
+        })
+    }
+
+    /// Fetches a page of the files changed by a pull request, with their patches. Only the first 3000 files can be listed.
+    pub async fn files(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<File>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/pulls/{}/files", owner, repo, number),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every file changed by a pull request, page by page.
+    pub fn files_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<File, Error>> + 'a {
+        let pulls = *self;
+        paginate_pages(page, limits, move |page| async move {
+            pulls.files(owner, repo, number, Some(page)).await
+        })
+    }
+
+    /// Checks if a pull request has been merged.
+    pub async fn is_merged(&self, owner: &str, repo: &str, number: usize) -> Result<bool, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/pulls/{}/merge", owner, repo, number),
+            ))
+            .await?
+            .exists()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Merges a pull request.
+    /// # Errors
+    /// Will error with `Error::Conflict` if `params.sha` is set and `head` has moved on from it,
+    /// and with `Error::Api` (`405 Method Not Allowed`) if the pull request can not be merged, like when checks are failing.
+    pub async fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        params: &MergeParams,
+    ) -> Result<MergeResult, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/repos/{}/{}/pulls/{}/merge", owner, repo, number),
+                    )
+                    .json(params)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Requests reviews from users and teams (by their slugs), and returns the updated pull request.
+    pub async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        reviewers: &[&str],
+        team_reviewers: &[&str],
+    ) -> Result<PullRequest, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/requested_reviewers",
+                            owner, repo, number
+                        ),
+                    )
+                    .json(&Reviewers {
+                        reviewers,
+                        team_reviewers,
+                    })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Takes back review requests from users and teams, and returns the updated pull request.
+    pub async fn remove_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        reviewers: &[&str],
+        team_reviewers: &[&str],
+    ) -> Result<PullRequest, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::DELETE,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/requested_reviewers",
+                            owner, repo, number
+                        ),
+                    )
+                    .json(&Reviewers {
+                        reviewers,
+                        team_reviewers,
+                    })?,
+            )
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{schema::pulls::FileStatus, test_utils::cassette_client};
+
+    #[tokio::test]
+    async fn get_works() {
+        let client = cassette_client("pulls/get");
+        let pull = client
+            .pulls()
+            .get("octocat", "Hello-World", 1347)
+            .await
+            .unwrap();
+        assert_eq!(pull.head.r#ref, "new-topic");
+        assert_eq!(pull.mergeable, Some(true));
+        assert!(!client
+            .pulls()
+            .is_merged("octocat", "Hello-World", 1347)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn files_have_patches() {
+        let client = cassette_client("pulls/files");
+        let files = client
+            .pulls()
+            .files("octocat", "Hello-World", 1347, None)
+            .await
+            .unwrap();
+        assert_eq!(files[0].status, FileStatus::Modified);
+        assert!(files[0].patch.as_deref().unwrap().starts_with("@@ -132,7"));
+        assert_eq!(files[1].status, FileStatus::Added);
+        assert_eq!(files[1].patch, None);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn merge_guards_the_sha() {
+        use crate::{
+            schema::pulls::{MergeMethod, MergeParams},
+            Error,
+        };
+        let client = cassette_client("pulls/merge");
+        let pulls = client.pulls();
+        let err = pulls
+            .merge(
+                "octocat",
+                "Hello-World",
+                1347,
+                &MergeParams::new()
+                    .method(MergeMethod::Squash)
+                    .sha("0000000000000000000000000000000000000000"),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Conflict(_)));
+        let merged = pulls
+            .merge(
+                "octocat",
+                "Hello-World",
+                1347,
+                &MergeParams::new()
+                    .method(MergeMethod::Squash)
+                    .sha("6dcb09b5b57875f334f61aebed695e2e4193db5e"),
+            )
+            .await
+            .unwrap();
+        assert!(merged.merged);
+        assert!(pulls
+            .is_merged("octocat", "Hello-World", 1347)
+            .await
+            .unwrap());
+    }
+}
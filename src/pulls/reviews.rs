@@ -0,0 +1,291 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::pulls::{NewReview, ReviewEvent};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::pulls::{Review, ReviewComment},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Body<'b> {
+    body: &'b str,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Submit<'b> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<&'b str>,
+    event: ReviewEvent,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Dismissal<'b> {
+    message: &'b str,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Reviews<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Reviews<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Reviews { client }
+    }
+
+    /// Fetches a page of the reviews of a pull request, oldest first.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Review>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, number),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every review of a pull request, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        number: usize,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Review, Error>> + 'a {
+        let reviews = *self;
+        paginate_pages(page, limits, move |page| async move {
+            reviews.list(owner, repo, number, Some(page)).await
+        })
+    }
+
+    /// Fetches a review by its id.
+    pub async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+    ) -> Result<Review, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!(
+                    "/repos/{}/{}/pulls/{}/reviews/{}",
+                    owner, repo, number, review_id
+                ),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the comments that are part of a review.
+    pub async fn comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        page: Option<PageParams>,
+    ) -> Result<Vec<ReviewComment>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+                            owner, repo, number, review_id
+                        ),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a review. Without `review.event`, it stays pending until `submit` is called.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review: &NewReview,
+    ) -> Result<Review, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/pulls/{}/reviews", owner, repo, number),
+                    )
+                    .json(review)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Replaces the body of a review.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        body: &str,
+    ) -> Result<Review, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/reviews/{}",
+                            owner, repo, number, review_id
+                        ),
+                    )
+                    .json(&Body { body })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Submits a pending review.
+    pub async fn submit(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        event: ReviewEvent,
+        body: Option<&str>,
+    ) -> Result<Review, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/reviews/{}/events",
+                            owner, repo, number, review_id
+                        ),
+                    )
+                    .json(&Submit { body, event })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a pending review, and returns it.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if the review has already been submitted.
+    pub async fn delete_pending(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+    ) -> Result<Review, Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!(
+                    "/repos/{}/{}/pulls/{}/reviews/{}",
+                    owner, repo, number, review_id
+                ),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Dismisses a submitted review, with a message on why. Needs admin access, or branch protection that allows it.
+    pub async fn dismiss(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: usize,
+        review_id: usize,
+        message: &str,
+    ) -> Result<Review, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!(
+                            "/repos/{}/{}/pulls/{}/reviews/{}/dismissals",
+                            owner, repo, number, review_id
+                        ),
+                    )
+                    .json(&Dismissal { message })?,
+            )
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::pulls::{NewReview, ReviewEvent, ReviewState},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn pending_reviews_can_be_submitted() {
+        let client = cassette_client("pulls/reviews");
+        let reviews = client.pulls().reviews();
+        let review = reviews
+            .create(
+                "octocat",
+                "Hello-World",
+                1347,
+                &NewReview::new().comment("file1.txt", 2, "Nice!"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(review.state, ReviewState::Pending);
+        assert_eq!(review.submitted_at, None);
+        let review = reviews
+            .submit(
+                "octocat",
+                "Hello-World",
+                1347,
+                review.id,
+                ReviewEvent::Approve,
+                Some("Ship it"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(review.state, ReviewState::Approved);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    schema::rate_limit::{Overview, RateLimit},
+    Error, GithubClient,
+};
+use chrono::{TimeZone, Utc};
+use reqwest::{header::HeaderMap, Method};
+
+#[non_exhaustive]
+pub struct RateLimits<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> RateLimits<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        RateLimits { client }
+    }
+
+    /// Fetches the rate limit status for every resource.
+    /// This does not count against the rate limit.
+    pub async fn get(&self) -> Result<Overview, Error> {
+        self.client
+            .send(self.client.request(Method::GET, "/rate_limit"))
+            .await?
+            .json()
+    }
+}
+
+impl RateLimit {
+    /// Reads the `X-RateLimit-*` headers. Returns `None` if any of the numeric ones are missing or malformed.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn number(headers: &HeaderMap, name: &str) -> Option<i64> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        }
+        Some(Self {
+            limit: number(headers, "x-ratelimit-limit")? as usize,
+            remaining: number(headers, "x-ratelimit-remaining")? as usize,
+            reset: Utc
+                .timestamp_opt(number(headers, "x-ratelimit-reset")?, 0)
+                .single()?,
+            used: number(headers, "x-ratelimit-used")? as usize,
+            resource: headers
+                .get("x-ratelimit-resource")
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::cassette_client;
+
+    #[test]
+    fn parses_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", "5000".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "4987".parse().unwrap());
+        headers.insert("X-RateLimit-Reset", "1620000000".parse().unwrap());
+        headers.insert("X-RateLimit-Used", "13".parse().unwrap());
+        headers.insert("X-RateLimit-Resource", "core".parse().unwrap());
+        let rate_limit = RateLimit::from_headers(&headers).unwrap();
+        assert_eq!(rate_limit.limit, 5000);
+        assert_eq!(rate_limit.remaining, 4987);
+        assert_eq!(rate_limit.reset.timestamp(), 1620000000);
+        assert_eq!(rate_limit.used, 13);
+        assert_eq!(rate_limit.resource.as_deref(), Some("core"));
+    }
+
+    #[test]
+    fn missing_headers_give_none() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", "5000".parse().unwrap());
+        assert_eq!(RateLimit::from_headers(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn get_works() {
+        let client = cassette_client("rate_limit/get");
+        let rate_limits = RateLimits::new(&client);
+        let data = rate_limits.get().await.unwrap();
+        assert_eq!(data.resources.core.limit, 5000);
+        assert_eq!(data.rate.remaining, 4999);
+        assert_eq!(data.resources.search.limit, 30);
+    }
+}
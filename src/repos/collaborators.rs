@@ -0,0 +1,203 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::{invitations::Invitation, NewPermission};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::repos::collaborators::{Affiliation, Collaborator, PermissionLevel},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct AffiliationParams {
+    affiliation: Affiliation,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct SetPermission {
+    permission: NewPermission,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Collaborators of repositories. Most of this needs push access to the repository.
+pub struct Collaborators<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Collaborators<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Collaborators { client }
+    }
+
+    /// Fetches a page of the collaborators of a repository. For repositories of organizations, this includes
+    /// the members that have access through the organization or a team, unless `affiliation` is `Direct` or `Outside`.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: Option<Affiliation>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Collaborator>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/collaborators", owner, repo),
+                    )
+                    .query(&affiliation.map(|affiliation| AffiliationParams { affiliation }))?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every collaborator of a repository, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        affiliation: Option<Affiliation>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Collaborator, Error>> + 'a {
+        let collaborators = *self;
+        paginate_pages(page, limits, move |page| async move {
+            collaborators
+                .list(owner, repo, affiliation, Some(page))
+                .await
+        })
+    }
+
+    /// Checks if a user is a collaborator of a repository, in any of the ways that `list` includes.
+    pub async fn is_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<bool, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/repos/{}/{}/collaborators/{}", owner, repo, username),
+            ))
+            .await?
+            .exists()
+    }
+
+    /// Fetches the access that a user has to a repository. Users without any access get `Permission::None`.
+    pub async fn permission(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<PermissionLevel, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!(
+                    "/repos/{}/{}/collaborators/{}/permission",
+                    owner, repo, username
+                ),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds a collaborator to a repository, or changes their permission.
+    /// Users that are not collaborators yet get an invitation, which is returned, and have access once they accept it.
+    /// Users that already are collaborators, or members of the organization that owns the repository, get the permission right away, and `None` is returned.
+    pub async fn add(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        permission: NewPermission,
+    ) -> Result<Option<Invitation>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/repos/{}/{}/collaborators/{}", owner, repo, username),
+                    )
+                    .json(&SetPermission { permission })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a collaborator from a repository. Access through an organization or a team is not affected.
+    pub async fn remove(&self, owner: &str, repo: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/repos/{}/{}/collaborators/{}", owner, repo, username),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::repos::{collaborators::Affiliation, NewPermission, Permission},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn collaborators_can_be_reviewed() {
+        let client = cassette_client("repos/collaborators");
+        let collaborators = client.repos().collaborators();
+        let invitation = collaborators
+            .add("octocat", "Hello-World", "hubot", NewPermission::Triage)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(invitation.permissions, Permission::Triage);
+        assert!(collaborators
+            .add("octocat", "Hello-World", "octocat", NewPermission::Admin)
+            .await
+            .unwrap()
+            .is_none());
+        let direct = collaborators
+            .list("octocat", "Hello-World", Some(Affiliation::Direct), None)
+            .await
+            .unwrap();
+        assert_eq!(direct[0].role_name.as_deref(), Some("admin"));
+        let level = collaborators
+            .permission("octocat", "Hello-World", "hubot")
+            .await
+            .unwrap();
+        assert_eq!(level.permission, Permission::None);
+        assert!(!collaborators
+            .is_collaborator("octocat", "Hello-World", "hubot")
+            .await
+            .unwrap());
+        collaborators
+            .remove("octocat", "Hello-World", "octocat")
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn permissions_take_both_names() {
+        let read: Permission = serde_json::from_str(r#""read""#).unwrap();
+        let pull: Permission = serde_json::from_str(r#""pull""#).unwrap();
+        assert_eq!(read, pull);
+        assert_eq!(
+            serde_json::to_string(&NewPermission::Write).unwrap(),
+            r#""push""#
+        );
+        assert!(Permission::Read < Permission::Write);
+    }
+}
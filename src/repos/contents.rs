@@ -0,0 +1,350 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::contents::{FileCommit, FileDelete, FileUpdate};
+use crate::{
+    schema::repos::contents::{ArchiveFormat, Content, File},
+    transport::encode_path,
+    Error, GithubClient,
+};
+use bytes::Bytes;
+use futures::{stream, Stream, TryStreamExt};
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Ref<'b> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#ref: Option<&'b str>,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Files and directories in a repository.
+pub struct Contents<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Contents<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Contents { client }
+    }
+
+    /// Fetches what is at `path`: a file with its decoded content, or the entries of a directory.
+    /// `r#ref` is a branch, tag, or commit SHA. The default branch is used otherwise.
+    /// # Errors
+    /// Will error if nothing is at `path`.
+    pub async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        r#ref: Option<&str>,
+    ) -> Result<Content, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+                    )
+                    .query(&Ref { r#ref })?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches the README of a repository, with its decoded content.
+    pub async fn readme(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: Option<&str>,
+    ) -> Result<File, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/readme", owner, repo))
+                    .query(&Ref { r#ref })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a file, or updates it if `file.sha` is set, in a new commit.
+    /// # Errors
+    /// Will error with `Error::Conflict` if `file.sha` is not the SHA of the file as it is now, and with `Error::Unprocessable` if it is missing for an existing file.
+    /// # Examples
+    /// ```rust,ignore
+    /// use use_github_api::schema::repos::contents::{Content, FileUpdate};
+    /// let contents = client.repos().contents();
+    /// if let Content::File(file) = contents.get("octocat", "config", "app.yml", None).await? {
+    ///     let yaml = String::from_utf8(file.content)?.replace("debug: true", "debug: false");
+    ///     contents
+    ///         .put("octocat", "config", "app.yml", &FileUpdate::new("Turn off debug", yaml).sha(file.sha))
+    ///         .await?;
+    /// }
+    /// ```
+    pub async fn put(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        file: &FileUpdate,
+    ) -> Result<FileCommit, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+                    )
+                    .json(file)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a file in a new commit.
+    /// # Errors
+    /// Will error with `Error::Conflict` if `file.sha` is not the SHA of the file as it is now.
+    pub async fn delete(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        file: &FileDelete,
+    ) -> Result<FileCommit, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::DELETE,
+                        &format!("/repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+                    )
+                    .json(file)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Downloads a tarball or zipball of the repository at `r#ref`, or at the default branch.
+    /// The archive comes in chunks as it is downloaded, so it never has to fit in memory. If the request fails, the error is the only item.
+    /// # Examples
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    /// use use_github_api::schema::repos::contents::ArchiveFormat;
+    /// let mut archive = Box::pin(client.repos().contents().archive("octocat", "Hello-World", ArchiveFormat::Tarball, None));
+    /// let mut file = tokio::fs::File::create("Hello-World.tar.gz").await?;
+    /// while let Some(chunk) = archive.try_next().await? {
+    ///     file.write_all(&chunk).await?;
+    /// }
+    /// ```
+    pub fn archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        format: ArchiveFormat,
+        r#ref: Option<&str>,
+    ) -> impl Stream<Item = Result<Bytes, Error>> + 'a {
+        let path = match r#ref {
+            Some(r#ref) => format!(
+                "/repos/{}/{}/{}/{}",
+                owner,
+                repo,
+                format.as_str(),
+                encode_path(r#ref)
+            ),
+            None => format!("/repos/{}/{}/{}", owner, repo, format.as_str()),
+        };
+        let client = self.client;
+        stream::once(async move {
+            client
+                .send_streaming(client.request(Method::GET, &path))
+                .await
+                .map(|response| response.body)
+        })
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{schema::repos::contents::EntryType, test_utils::cassette_client};
+
+    #[tokio::test]
+    async fn get_decodes_files() {
+        let client = cassette_client("repos/contents_file");
+        let content = client
+            .repos()
+            .contents()
+            .get("octocat", "config", "app.yml", Some("main"))
+            .await
+            .unwrap();
+        match content {
+            Content::File(file) => assert_eq!(file.content, b"name: app\ndebug: true\n"),
+            other => panic!("expected a file, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_lists_directories() {
+        let client = cassette_client("repos/contents_dir");
+        let content = client
+            .repos()
+            .contents()
+            .get("octocat", "config", "deploy", None)
+            .await
+            .unwrap();
+        match content {
+            Content::Directory(entries) => {
+                assert_eq!(entries.len(), 2);
+                assert_eq!(entries[1].r#type, EntryType::Dir);
+            }
+            other => panic!("expected a directory, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn put_encodes_content() {
+        let client = cassette_client("repos/contents_put");
+        let commit = client
+            .repos()
+            .contents()
+            .put(
+                "octocat",
+                "config",
+                "app.yml",
+                &FileUpdate::new("Turn off debug", "name: app\ndebug: false\n")
+                    .sha("3d21ec53a331a6f037a91c368710b99387d012c1"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(commit.commit.message, "Turn off debug");
+        assert_eq!(commit.content.unwrap().path, "app.yml");
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn delete_works() {
+        let client = cassette_client("repos/contents_delete");
+        let commit = client
+            .repos()
+            .contents()
+            .delete(
+                "octocat",
+                "config",
+                "old.yml",
+                &FileDelete::new(
+                    "Remove old config",
+                    "7ebe9c2a2f0bc4c9a4fa76a8ea5fbac1e9a1a8f2",
+                ),
+            )
+            .await
+            .unwrap();
+        assert_eq!(commit.content, None);
+    }
+
+    #[tokio::test]
+    async fn archive_keeps_binary_bodies() {
+        let client = cassette_client("repos/contents_archive");
+        let chunks: Vec<Bytes> = client
+            .repos()
+            .contents()
+            .archive("octocat", "config", ArchiveFormat::Tarball, Some("v1.0"))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(&chunks.concat()[..4], &[0x1f, 0x8b, 0x08, 0x00]);
+    }
+
+    #[tokio::test]
+    async fn archive_streams_in_chunks() {
+        use crate::test_utils::fake_server;
+        let (base_url, _) = fake_server(vec![Some(
+            "HTTP/1.1 200 OK\r\ntransfer-encoding: chunked\r\n\r\n4\r\nPK\x03\x04\r\n3\r\nabc\r\n0\r\n\r\n",
+        )])
+        .await;
+        let mut client = crate::test_utils::mock_client(&crate::transport::MockTransport::new())
+            .with_transport(crate::transport::ReqwestTransport::new());
+        client.base_url = base_url.into();
+        let chunks: Vec<Bytes> = client
+            .repos()
+            .contents()
+            .archive("octocat", "config", ArchiveFormat::Zipball, None)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.concat(), b"PK\x03\x04abc");
+    }
+
+    #[tokio::test]
+    async fn archive_errors_are_the_only_item() {
+        use crate::transport::{MockTransport, Response};
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/repos/octocat/nope/tarball",
+            Response::new(
+                reqwest::StatusCode::NOT_FOUND,
+                r#"{ "message": "Not Found" }"#,
+            ),
+        );
+        let client = crate::test_utils::mock_client(&mock);
+        let items: Vec<Result<Bytes, Error>> = futures::StreamExt::collect(
+            client
+                .repos()
+                .contents()
+                .archive("octocat", "nope", ArchiveFormat::Tarball, None),
+        )
+        .await;
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(Error::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn paths_are_encoded_by_segment() {
+        use crate::transport::{MockTransport, Response};
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/repos/octocat/config/contents/docs/C%23%20notes/100%25%3F.md",
+            Response::new(reqwest::StatusCode::OK, "[]"),
+        );
+        let client = crate::test_utils::mock_client(&mock);
+        client
+            .repos()
+            .contents()
+            .get("octocat", "config", "docs/C# notes/100%?.md", None)
+            .await
+            .unwrap();
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn bad_file_content_is_a_decode_error() {
+        use crate::transport::{MockTransport, Response};
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/repos/octocat/config/contents/app.yml",
+            Response::new(
+                reqwest::StatusCode::OK,
+                r#"{ "type": "file", "size": 3, "name": "app.yml", "path": "app.yml", "sha": "3d21ec53a331a6f037a91c368710b99387d012c1", "content": "not base64!", "download_url": null }"#,
+            ),
+        );
+        let client = crate::test_utils::mock_client(&mock);
+        let err = client
+            .repos()
+            .contents()
+            .get("octocat", "config", "app.yml", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Decode(_)));
+    }
+}
@@ -0,0 +1,441 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::{NewRepo, UpdateRepo};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::repos::{Contributor, Languages, ListParams, Repository, Tag},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::{Method, StatusCode};
+use serde::Deserialize;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+pub mod collaborators;
+pub mod contents;
+pub mod statuses;
+use collaborators::Collaborators;
+use contents::Contents;
+use statuses::Statuses;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct Transfer<'b> {
+    new_owner: &'b str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_ids: Option<&'b [usize]>,
+}
+
+#[derive(Deserialize)]
+struct Topics {
+    names: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct Repos<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Repos<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Repos { client }
+    }
+
+    /// Collaborators of repositories, and the access that users have to them.
+    pub fn collaborators(&self) -> Collaborators<'a> {
+        Collaborators::new(self.client)
+    }
+
+    /// Files and directories in repositories.
+    pub fn contents(&self) -> Contents<'a> {
+        Contents::new(self.client)
+    }
+
+    /// Commit statuses.
+    pub fn statuses(&self) -> Statuses<'a> {
+        Statuses::new(self.client)
+    }
+
+    /// Fetches a repository.
+    /// # Errors
+    /// Will error if the repository does not exist, or if it is private and the current user cannot see it.
+    pub async fn get(&self, owner: &str, repo: &str) -> Result<Repository, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}", owner, repo)),
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the public repositories of a user.
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/repos", username))
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every public repository of a user, page by page.
+    pub fn list_for_user_stream(
+        &self,
+        username: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Repository, Error>> + 'a {
+        let repos = *self;
+        paginate_pages(page, limits, move |page| async move {
+            repos.list_for_user(username, params, Some(page)).await
+        })
+    }
+
+    /// Fetches a page of the repositories of an organization.
+    pub async fn list_for_org(
+        &self,
+        org: &str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/repos", org))
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every repository of an organization, page by page.
+    pub fn list_for_org_stream(
+        &self,
+        org: &'a str,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Repository, Error>> + 'a {
+        let repos = *self;
+        paginate_pages(page, limits, move |page| async move {
+            repos.list_for_org(org, params, Some(page)).await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the repositories that the current authenticated user can access, including private ones.
+    pub async fn list(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/repos")
+                    .query(&params)?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every repository that the current authenticated user can access, page by page.
+    pub fn list_stream(
+        &self,
+        params: Option<ListParams>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Repository, Error>> + 'a {
+        let repos = *self;
+        paginate_pages(page, limits, move |page| async move {
+            repos.list(params, Some(page)).await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a repository for the current authenticated user.
+    /// # Errors
+    /// Will error if a repository with the same name already exists.
+    /// # Examples
+    /// ```rust,ignore
+    /// use use_github_api::schema::repos::NewRepo;
+    /// let repo = client.repos().create(&NewRepo::new("config").private(true)).await?;
+    /// ```
+    pub async fn create(&self, repo: &NewRepo) -> Result<Repository, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, "/user/repos")
+                    .json(repo)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a repository in an organization. The current authenticated user has to be a member of it.
+    /// # Errors
+    /// Will error if a repository with the same name already exists.
+    pub async fn create_for_org(&self, org: &str, repo: &NewRepo) -> Result<Repository, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/orgs/{}/repos", org))
+                    .json(repo)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes the settings of a repository, and returns the updated repository.
+    pub async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        changes: &UpdateRepo,
+    ) -> Result<Repository, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PATCH, &format!("/repos/{}/{}", owner, repo))
+                    .json(changes)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a repository. Needs the `delete_repo` scope.
+    pub async fn delete(&self, owner: &str, repo: &str) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::DELETE, &format!("/repos/{}/{}", owner, repo)),
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Starts transferring a repository to another user or organization, and returns the repository as it is now.
+    /// The transfer itself happens in the background.
+    /// `team_ids` are the teams that get access to the repository, when transferring to an organization.
+    pub async fn transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+        new_owner: &str,
+        team_ids: Option<&[usize]>,
+    ) -> Result<Repository, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/repos/{}/{}/transfer", owner, repo))
+                    .json(&Transfer {
+                        new_owner,
+                        team_ids,
+                    })?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches the languages of a repository, with how many bytes of code are in each.
+    pub async fn languages(&self, owner: &str, repo: &str) -> Result<Languages, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/languages", owner, repo)),
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches the topics of a repository.
+    pub async fn topics(&self, owner: &str, repo: &str) -> Result<Vec<String>, Error> {
+        let topics: Topics = self
+            .client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/topics", owner, repo)),
+            )
+            .await?
+            .json()?;
+        Ok(topics.names)
+    }
+
+    /// Fetches a page of the contributors of a repository, sorted by how many commits they have.
+    /// An empty repository has no contributors, which GitHub answers with `204 No Content`.
+    pub async fn contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Contributor>, Error> {
+        let response = self
+            .client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/repos/{}/{}/contributors", owner, repo),
+                    )
+                    .query(&page)?,
+            )
+            .await?;
+        if response.status == StatusCode::NO_CONTENT {
+            return Ok(Vec::new());
+        }
+        response.json()
+    }
+
+    /// Fetches every contributor of a repository, page by page.
+    pub fn contributors_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Contributor, Error>> + 'a {
+        let repos = *self;
+        paginate_pages(page, limits, move |page| async move {
+            repos.contributors(owner, repo, Some(page)).await
+        })
+    }
+
+    /// Fetches a page of the tags of a repository.
+    pub async fn tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Tag>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/repos/{}/{}/tags", owner, repo))
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every tag of a repository, page by page.
+    pub fn tags_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Tag, Error>> + 'a {
+        let repos = *self;
+        paginate_pages(page, limits, move |page| async move {
+            repos.tags(owner, repo, Some(page)).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{schema::repos::Type, test_utils::cassette_client};
+
+    #[tokio::test]
+    async fn get_works() {
+        let client = cassette_client("repos/get");
+        let data = client.repos().get("octocat", "Hello-World").await.unwrap();
+        assert_eq!(data.full_name, "octocat/Hello-World");
+        assert_eq!(data.owner.login, "octocat");
+        assert_eq!(data.license.unwrap().spdx_id.as_deref(), Some("MIT"));
+        #[cfg(feature = "node_ids")]
+        assert_eq!(data.node_id, "MDEwOlJlcG9zaXRvcnkxMjk2MjY5");
+    }
+
+    #[tokio::test]
+    async fn list_for_user_stream_sends_the_filters() {
+        use futures::TryStreamExt;
+        let client = cassette_client("repos/list_for_user");
+        let data: Vec<Repository> = client
+            .repos()
+            .list_for_user_stream(
+                "octocat",
+                Some(ListParams::new().r#type(Type::Owner)),
+                None,
+                Limits::new(),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn empty_repos_have_no_contributors() {
+        let client = cassette_client("repos/contributors_empty");
+        let contributors = client
+            .repos()
+            .contributors("octocat", "empty", None)
+            .await
+            .unwrap();
+        assert!(contributors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn languages_and_topics_work() {
+        let client = cassette_client("repos/languages_and_topics");
+        let repos = client.repos();
+        let languages = repos.languages("octocat", "Hello-World").await.unwrap();
+        assert_eq!(languages["Rust"], 12345);
+        let topics = repos.topics("octocat", "Hello-World").await.unwrap();
+        assert_eq!(topics, vec!["octocat", "api"]);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn create_update_and_delete_work() {
+        use crate::schema::repos::UpdateRepo;
+        let client = cassette_client("repos/manage");
+        let repos = client.repos();
+        let repo = repos
+            .create(&NewRepo::new("Hello-World").private(true))
+            .await
+            .unwrap();
+        assert!(repo.private);
+        let repo = repos
+            .update(
+                "octocat",
+                "Hello-World",
+                &UpdateRepo::new().description("My first repository"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(repo.description.as_deref(), Some("My first repository"));
+        repos
+            .transfer("octocat", "Hello-World", "github", Some(&[1]))
+            .await
+            .unwrap();
+        repos.delete("github", "Hello-World").await.unwrap();
+    }
+}
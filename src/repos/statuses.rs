@@ -0,0 +1,180 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::statuses::NewStatus;
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::repos::statuses::{CombinedStatus, Status},
+    transport::encode_path,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Commit statuses, the older and simpler alternative to check runs.
+pub struct Statuses<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Statuses<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Statuses { client }
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a status on a commit. Needs push access.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if the commit already has 1000 statuses for the same context.
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        status: &NewStatus,
+    ) -> Result<Status, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::POST,
+                        &format!("/repos/{}/{}/statuses/{}", owner, repo, sha),
+                    )
+                    .json(status)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the statuses of a commit, newest first. `r#ref` is a branch, tag, or commit SHA.
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+        page: Option<PageParams>,
+    ) -> Result<Vec<Status>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!(
+                            "/repos/{}/{}/commits/{}/statuses",
+                            owner,
+                            repo,
+                            encode_path(r#ref)
+                        ),
+                    )
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every status of a commit, page by page.
+    pub fn list_stream(
+        &self,
+        owner: &'a str,
+        repo: &'a str,
+        r#ref: &'a str,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Status, Error>> + 'a {
+        let statuses = *self;
+        paginate_pages(page, limits, move |page| async move {
+            statuses.list(owner, repo, r#ref, Some(page)).await
+        })
+    }
+
+    /// Fetches the combined status of a commit. `r#ref` is a branch, tag, or commit SHA.
+    pub async fn combined(
+        &self,
+        owner: &str,
+        repo: &str,
+        r#ref: &str,
+    ) -> Result<CombinedStatus, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!(
+                    "/repos/{}/{}/commits/{}/status",
+                    owner,
+                    repo,
+                    encode_path(r#ref)
+                ),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::repos::statuses::{NewStatus, State},
+        test_utils::{cassette_client, mock_client},
+        transport::{MockTransport, Response},
+    };
+    use reqwest::{Method, StatusCode};
+
+    #[tokio::test]
+    async fn statuses_combine() {
+        let client = cassette_client("repos/statuses");
+        let statuses = client.repos().statuses();
+        let status = statuses
+            .create(
+                "octocat",
+                "Hello-World",
+                "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                &NewStatus::new(State::Failure)
+                    .context("ci/lint")
+                    .description("2 errors"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(status.context, "ci/lint");
+        let combined = statuses
+            .combined("octocat", "Hello-World", "main")
+            .await
+            .unwrap();
+        assert_eq!(combined.state, State::Failure);
+        assert_eq!(combined.statuses.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn commits_without_statuses_are_pending() {
+        let client = cassette_client("repos/statuses_none");
+        let combined = client
+            .repos()
+            .statuses()
+            .combined("octocat", "Hello-World", "main")
+            .await
+            .unwrap();
+        assert_eq!(combined.state, State::Pending);
+        assert_eq!(combined.total_count, 0);
+        assert!(combined.statuses.is_empty());
+    }
+
+    #[tokio::test]
+    async fn refs_are_encoded() {
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/repos/octocat/Hello-World/commits/feature/x%231/status",
+            Response::new(
+                StatusCode::OK,
+                r#"{ "state": "pending", "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e", "total_count": 0, "statuses": [] }"#,
+            ),
+        );
+        let client = mock_client(&mock);
+        let combined = client
+            .repos()
+            .statuses()
+            .combined("octocat", "Hello-World", "feature/x#1")
+            .await
+            .unwrap();
+        assert_eq!(combined.state, State::Pending);
+    }
+}
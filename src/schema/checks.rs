@@ -0,0 +1,526 @@
+use crate::{Error, ValidationError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The most annotations that GitHub takes in a single request.
+#[cfg(feature = "auth")]
+pub(crate) const MAX_ANNOTATIONS: usize = 50;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Queued,
+    InProgress,
+    Completed,
+    Waiting,
+    Requested,
+    Pending,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Conclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Success,
+    Skipped,
+    Stale,
+    TimedOut,
+    StartupFailure,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationLevel {
+    Notice,
+    Warning,
+    Failure,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A note on lines of a file, shown in the diff and in the checks tab.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::checks::{Annotation, AnnotationLevel};
+/// let annotation = Annotation::new("src/lib.rs", 2, 2, AnnotationLevel::Warning, "unused variable: `x`")
+///     .title("unused_variables")
+///     .columns(9, 10);
+/// ```
+pub struct Annotation {
+    pub path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    /// Only allowed when `start_line` and `end_line` are the same.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+    pub annotation_level: AnnotationLevel,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details: Option<String>,
+}
+
+impl Annotation {
+    /// Creates a new `Annotation` on the lines from `start_line` to `end_line` of `path`.
+    pub fn new(
+        path: impl Into<String>,
+        start_line: usize,
+        end_line: usize,
+        annotation_level: AnnotationLevel,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            start_line,
+            end_line,
+            start_column: None,
+            end_column: None,
+            annotation_level,
+            message: message.into(),
+            title: None,
+            raw_details: None,
+        }
+    }
+
+    /// Narrows the annotation down to columns of its line.
+    pub fn columns(mut self, start_column: usize, end_column: usize) -> Self {
+        self.start_column = Some(start_column);
+        self.end_column = Some(end_column);
+        self
+    }
+
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets details that are shown when the annotation is expanded, like a full compiler message.
+    pub fn raw_details(mut self, raw_details: impl Into<String>) -> Self {
+        self.raw_details = Some(raw_details.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// The report of a check run. There can be any amount of annotations: they are sent 50 at a time, which is the most that GitHub takes in a request.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::checks::{Annotation, AnnotationLevel, Output};
+/// let output = Output::new("Clippy", "1 warning")
+///     .annotation(Annotation::new("src/lib.rs", 2, 2, AnnotationLevel::Warning, "unused variable: `x`"));
+/// ```
+pub struct Output {
+    pub title: String,
+    /// Supports Markdown.
+    pub summary: String,
+    /// Supports Markdown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<Annotation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<Image>,
+}
+
+impl Output {
+    /// Creates a new `Output` with a title and a summary.
+    pub fn new(title: impl Into<String>, summary: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            summary: summary.into(),
+            text: None,
+            annotations: Vec::new(),
+            images: Vec::new(),
+        }
+    }
+
+    /// Sets the details.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Adds an annotation.
+    pub fn annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    /// Adds annotations.
+    pub fn annotations(mut self, annotations: impl IntoIterator<Item = Annotation>) -> Self {
+        self.annotations.extend(annotations);
+        self
+    }
+
+    /// Adds an image.
+    pub fn image(mut self, image: Image) -> Self {
+        self.images.push(image);
+        self
+    }
+
+    /// Splits the output so that each part has at most 50 annotations. There is always at least one part.
+    /// Every part has the title and summary, since GitHub requires them, and GitHub appends the annotations of each part to the ones before.
+    /// GitHub appends images too, so they are only in the first part, along with the text.
+    #[cfg(feature = "auth")]
+    pub(crate) fn batches(&self) -> Vec<Output> {
+        let mut chunks = self.annotations.chunks(MAX_ANNOTATIONS);
+        let first = Output {
+            annotations: chunks.next().map(<[_]>::to_vec).unwrap_or_default(),
+            ..self.clone()
+        };
+        std::iter::once(first)
+            .chain(chunks.map(|annotations| Output {
+                annotations: annotations.to_vec(),
+                ..Output::new(self.title.clone(), self.summary.clone())
+            }))
+            .collect()
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// An image shown in the output of a check run.
+pub struct Image {
+    pub alt: String,
+    pub image_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+impl Image {
+    /// Creates a new `Image` from its alternative text and its URL.
+    pub fn new(alt: impl Into<String>, image_url: impl Into<String>) -> Self {
+        Self {
+            alt: alt.into(),
+            image_url: image_url.into(),
+            caption: None,
+        }
+    }
+
+    /// Sets a short description shown with the image.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// The report of a check run, without the annotations, which are fetched separately.
+pub struct OutputSummary {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub text: Option<String>,
+    pub annotations_count: usize,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SuiteId {
+    pub id: usize,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CheckRun {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub name: String,
+    pub head_sha: String,
+    pub external_id: Option<String>,
+    pub details_url: Option<String>,
+    pub status: Status,
+    /// Only set once the check run is completed.
+    pub conclusion: Option<Conclusion>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub output: OutputSummary,
+    pub check_suite: Option<SuiteId>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CheckRunList {
+    pub total_count: usize,
+    pub check_runs: Vec<CheckRun>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CheckSuite {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub status: Option<Status>,
+    pub conclusion: Option<Conclusion>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub latest_check_runs_count: usize,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CheckSuiteList {
+    pub total_count: usize,
+    pub check_suites: Vec<CheckSuite>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunFilter {
+    /// Only the latest check run of each name.
+    Latest,
+    All,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Filters for listing check runs. Pagination is set separately, with `PageParams`.
+pub struct RunListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<RunFilter>,
+}
+
+impl RunListParams {
+    /// Creates a new `RunListParams` that only lists the latest check runs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists check runs with a name.
+    pub fn check_name(mut self, check_name: impl Into<String>) -> Self {
+        self.check_name = Some(check_name.into());
+        self
+    }
+
+    /// Only lists check runs with a status.
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets whether only the latest check run of each name is listed.
+    pub fn filter(mut self, filter: RunFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Filters for listing check suites. Pagination is set separately, with `PageParams`.
+pub struct SuiteListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_name: Option<String>,
+}
+
+impl SuiteListParams {
+    /// Creates a new `SuiteListParams` that lists every check suite.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists the check suites of an app.
+    pub fn app_id(mut self, app_id: usize) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    /// Only lists check suites with a check run of a name.
+    pub fn check_name(mut self, check_name: impl Into<String>) -> Self {
+        self.check_name = Some(check_name.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A check run to create. Only GitHub Apps can create check runs.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::checks::{Conclusion, NewCheckRun, Output};
+/// let run = NewCheckRun::new("clippy", "ce587453ced02b1526dfb4cb910479d431683101")
+///     .unwrap()
+///     .conclusion(Conclusion::Success)
+///     .output(Output::new("Clippy", "No warnings"));
+/// ```
+pub struct NewCheckRun {
+    pub name: String,
+    pub head_sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    /// Setting this completes the check run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<Conclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Output>,
+}
+
+impl NewCheckRun {
+    /// Creates a new `NewCheckRun` for a commit.
+    /// # Errors
+    /// Will error if the name is empty.
+    pub fn new(name: impl Into<String>, head_sha: impl Into<String>) -> Result<Self, Error> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "name" }.into());
+        }
+        Ok(Self {
+            name,
+            head_sha: head_sha.into(),
+            details_url: None,
+            external_id: None,
+            status: None,
+            started_at: None,
+            conclusion: None,
+            completed_at: None,
+            output: None,
+        })
+    }
+
+    /// Sets the URL of the full details of the check run, on the integrator's site.
+    pub fn details_url(mut self, details_url: impl Into<String>) -> Self {
+        self.details_url = Some(details_url.into());
+        self
+    }
+
+    /// Sets an id of the integrator's own.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Sets the status. Defaults to `Queued`.
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets when the check run started.
+    pub fn started_at(mut self, started_at: DateTime<Utc>) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Sets the conclusion, which completes the check run.
+    pub fn conclusion(mut self, conclusion: Conclusion) -> Self {
+        self.conclusion = Some(conclusion);
+        self
+    }
+
+    /// Sets when the check run completed.
+    pub fn completed_at(mut self, completed_at: DateTime<Utc>) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    /// Sets the report.
+    pub fn output(mut self, output: Output) -> Self {
+        self.output = Some(output);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The changes to make with `Runs::update`. Fields that are not set are left as they are, and annotations are added to the existing ones.
+pub struct UpdateCheckRun {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<Conclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Output>,
+}
+
+impl UpdateCheckRun {
+    /// Creates a new `UpdateCheckRun` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the check run.
+    /// # Errors
+    /// Will error if the name is empty.
+    pub fn name(mut self, name: impl Into<String>) -> Result<Self, Error> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "name" }.into());
+        }
+        self.name = Some(name);
+        Ok(self)
+    }
+
+    /// Sets the URL of the full details of the check run, on the integrator's site.
+    pub fn details_url(mut self, details_url: impl Into<String>) -> Self {
+        self.details_url = Some(details_url.into());
+        self
+    }
+
+    /// Sets an id of the integrator's own.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Sets the status.
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets when the check run started.
+    pub fn started_at(mut self, started_at: DateTime<Utc>) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Sets the conclusion, which completes the check run.
+    pub fn conclusion(mut self, conclusion: Conclusion) -> Self {
+        self.conclusion = Some(conclusion);
+        self
+    }
+
+    /// Sets when the check run completed.
+    pub fn completed_at(mut self, completed_at: DateTime<Utc>) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    /// Sets the report.
+    pub fn output(mut self, output: Output) -> Self {
+        self.output = Some(output);
+        self
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    schema::{repos::contents::decode, GitUser},
+    Error, ValidationError,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectType {
+    Blob,
+    Tree,
+    Commit,
+    Tag,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A pointer to a Git object, like the tree or the parents of a commit.
+pub struct ShaRef {
+    pub sha: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// The object that a ref or a tag points to.
+pub struct Object {
+    pub sha: String,
+    pub r#type: ObjectType,
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub verified: bool,
+    pub reason: String,
+    pub signature: Option<String>,
+    pub payload: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A blob, with its content already decoded from base64.
+pub struct Blob {
+    pub sha: String,
+    pub size: usize,
+    #[serde(deserialize_with = "decode")]
+    pub content: Vec<u8>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+/// The file mode of a tree entry.
+pub enum Mode {
+    #[serde(rename = "100644")]
+    File,
+    #[serde(rename = "100755")]
+    Executable,
+    #[serde(rename = "040000")]
+    Subdirectory,
+    #[serde(rename = "160000")]
+    Submodule,
+    #[serde(rename = "120000")]
+    Symlink,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: Mode,
+    pub r#type: ObjectType,
+    pub sha: String,
+    /// Only set for blobs.
+    pub size: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Tree {
+    pub sha: String,
+    pub tree: Vec<TreeEntry>,
+    /// Whether GitHub left entries out, which it does when a recursive tree has over 100,000 entries or 7 MB.
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// An entry of a tree to create: an existing object, a file with text content, or a deletion of a path in the base tree.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::git::{Mode, NewTreeEntry};
+/// let entries = vec![
+///     NewTreeEntry::file("README.md", "# Hello World\n"),
+///     NewTreeEntry::blob("build.sh", "3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15").mode(Mode::Executable),
+///     NewTreeEntry::delete("old.txt"),
+/// ];
+/// ```
+pub struct NewTreeEntry {
+    pub path: String,
+    pub mode: Mode,
+    pub r#type: ObjectType,
+    /// `Some(None)` is sent as `null`, which deletes the path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<Option<String>>,
+    /// Has to be UTF-8. GitHub creates a blob for it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl NewTreeEntry {
+    /// Creates an entry that points to an existing blob.
+    pub fn blob(path: impl Into<String>, sha: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: Mode::File,
+            r#type: ObjectType::Blob,
+            sha: Some(Some(sha.into())),
+            content: None,
+        }
+    }
+
+    /// Creates an entry that points to an existing tree, as a subdirectory.
+    pub fn tree(path: impl Into<String>, sha: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: Mode::Subdirectory,
+            r#type: ObjectType::Tree,
+            sha: Some(Some(sha.into())),
+            content: None,
+        }
+    }
+
+    /// Creates an entry for a file with text content.
+    pub fn file(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: Mode::File,
+            r#type: ObjectType::Blob,
+            sha: None,
+            content: Some(content.into()),
+        }
+    }
+
+    /// Creates an entry that deletes a file of the base tree.
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            mode: Mode::File,
+            r#type: ObjectType::Blob,
+            sha: Some(None),
+            content: None,
+        }
+    }
+
+    /// Sets the mode, like `Executable` for scripts.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A tree to create. Without a base tree, paths that are not in `tree` are not in the new tree either.
+pub struct NewTree {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_tree: Option<String>,
+    pub tree: Vec<NewTreeEntry>,
+}
+
+impl NewTree {
+    /// Creates a new `NewTree` with entries.
+    pub fn new(tree: Vec<NewTreeEntry>) -> Self {
+        Self {
+            base_tree: None,
+            tree,
+        }
+    }
+
+    /// Sets the tree that the entries are applied on top of.
+    pub fn base_tree(mut self, base_tree: impl Into<String>) -> Self {
+        self.base_tree = Some(base_tree.into());
+        self
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    pub sha: String,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub author: GitUser,
+    pub committer: GitUser,
+    pub message: String,
+    pub tree: ShaRef,
+    pub parents: Vec<ShaRef>,
+    pub verification: Option<Verification>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A commit to create.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::{git::NewCommit, GitUser};
+/// let commit = NewCommit::new(
+///     "Update the README",
+///     "827efc6d56897b048c772eb4087f854f46256132",
+///     vec!["7d1b31e74ee336d15cbd21741bc88a537ed063a0".to_owned()],
+/// )
+/// .unwrap()
+/// .author(GitUser::new("Mona Octocat", "octocat@github.com"));
+/// ```
+pub struct NewCommit {
+    pub message: String,
+    pub tree: String,
+    pub parents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<GitUser>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<GitUser>,
+    /// An ASCII-armored detached signature of the commit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl NewCommit {
+    /// Creates a new `NewCommit` with a message, the SHA of its tree, and the SHAs of its parents (none for a root commit).
+    /// # Errors
+    /// Will error if the message is empty.
+    pub fn new(
+        message: impl Into<String>,
+        tree: impl Into<String>,
+        parents: Vec<String>,
+    ) -> Result<Self, Error> {
+        let message = message.into();
+        if message.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "message" }.into());
+        }
+        Ok(Self {
+            message,
+            tree: tree.into(),
+            parents,
+            author: None,
+            committer: None,
+            signature: None,
+        })
+    }
+
+    /// Sets the author. The current authenticated user is used otherwise.
+    pub fn author(mut self, author: GitUser) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the committer. The author is used otherwise.
+    pub fn committer(mut self, committer: GitUser) -> Self {
+        self.committer = Some(committer);
+        self
+    }
+
+    /// Sets the signature.
+    pub fn signature(mut self, signature: impl Into<String>) -> Self {
+        self.signature = Some(signature.into());
+        self
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Ref {
+    /// The full name, like `refs/heads/main`.
+    pub r#ref: String,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub url: String,
+    pub object: Object,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// An annotated tag object.
+pub struct Tag {
+    pub tag: String,
+    pub sha: String,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub url: String,
+    pub message: String,
+    pub tagger: GitUser,
+    pub object: Object,
+    pub verification: Option<Verification>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// An annotated tag to create. This only creates the tag object: a `refs/tags/...` ref that points to it has to be created with `Git::create_ref` as well.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::{git::{NewTag, ObjectType}, GitUser};
+/// let tag = NewTag::new("v0.0.1", "initial version", "c3d0be41ecbe669545ee3e94d31ed9a4bc91ee3c", ObjectType::Commit)
+///     .unwrap()
+///     .tagger(GitUser::new("Monalisa Octocat", "octocat@github.com"));
+/// ```
+pub struct NewTag {
+    pub tag: String,
+    pub message: String,
+    pub object: String,
+    pub r#type: ObjectType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagger: Option<GitUser>,
+}
+
+impl NewTag {
+    /// Creates a new `NewTag` with a name, a message, and the object it points to.
+    /// # Errors
+    /// Will error if the name is empty.
+    pub fn new(
+        tag: impl Into<String>,
+        message: impl Into<String>,
+        object: impl Into<String>,
+        r#type: ObjectType,
+    ) -> Result<Self, Error> {
+        let tag = tag.into();
+        if tag.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "tag" }.into());
+        }
+        Ok(Self {
+            tag,
+            message: message.into(),
+            object: object.into(),
+            r#type,
+            tagger: None,
+        })
+    }
+
+    /// Sets the tagger. The current authenticated user is used otherwise.
+    pub fn tagger(mut self, tagger: GitUser) -> Self {
+        self.tagger = Some(tagger);
+        self
+    }
+}
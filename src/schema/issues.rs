@@ -0,0 +1,621 @@
+use super::users::list;
+use crate::{Error, ValidationError};
+use chrono::{DateTime, Utc};
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+
+pub use super::repos::Direction;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum State {
+    Open,
+    Closed,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+/// Which issues to list, by state.
+pub enum StateFilter {
+    Open,
+    Closed,
+    All,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+/// Which issues to list, by how the current authenticated user is involved. Only used when listing across repositories.
+pub enum Filter {
+    Assigned,
+    Created,
+    Mentioned,
+    Subscribed,
+    Repos,
+    All,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Sort {
+    Created,
+    Updated,
+    Comments,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")]
+    OffTopic,
+    #[serde(rename = "too heated")]
+    TooHeated,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "spam")]
+    Spam,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub name: String,
+    /// Hex, without the `#`.
+    pub color: String,
+    pub description: Option<String>,
+    pub default: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Milestone {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub number: usize,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: State,
+    pub creator: Option<list::User>,
+    pub open_issues: usize,
+    pub closed_issues: usize,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Issue {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub number: usize,
+    pub title: String,
+    pub body: Option<String>,
+    pub user: list::User,
+    pub labels: Vec<Label>,
+    pub state: State,
+    pub locked: bool,
+    pub active_lock_reason: Option<LockReason>,
+    pub assignees: Vec<list::User>,
+    pub milestone: Option<Milestone>,
+    pub comments: usize,
+    pub author_association: String,
+    pub closed_by: Option<list::User>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pull_request: Option<IgnoredAny>,
+}
+
+impl Issue {
+    /// Every pull request is an issue too, so lists of issues include pull requests.
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Comment {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub body: String,
+    pub user: list::User,
+    pub author_association: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Filters and sorting for listing issues. Pagination is set separately, with `PageParams`.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::issues::{ListParams, StateFilter};
+/// let params = ListParams::new()
+///     .state(StateFilter::All)
+///     .labels(&["bug", "needs triage"]);
+/// ```
+pub struct ListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Filter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateFilter>,
+    /// Comma separated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<String>,
+    /// A username, `none` for issues without an assignee, or `*` for issues with any assignee.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+}
+
+impl ListParams {
+    /// Creates a new `ListParams` with GitHub's defaults (open issues, newest first).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists issues that the current authenticated user is involved with in a certain way.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Only lists issues in a state.
+    pub fn state(mut self, state: StateFilter) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Only lists issues that have all of these labels.
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.labels = Some(labels.join(","));
+        self
+    }
+
+    /// Only lists issues assigned to a user.
+    pub fn assignee(mut self, assignee: impl Into<String>) -> Self {
+        self.assignee = Some(assignee.into());
+        self
+    }
+
+    /// Only lists issues created by a user.
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.creator = Some(creator.into());
+        self
+    }
+
+    /// Only lists issues updated at or after a time.
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets what to sort by.
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the sort direction.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// An issue to create.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::issues::NewIssue;
+/// let issue = NewIssue::new("Crash on startup").unwrap().labels(&["bug"]);
+/// ```
+pub struct NewIssue {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+}
+
+impl NewIssue {
+    /// Creates a new `NewIssue` with a title.
+    /// # Errors
+    /// Will error if the title is empty.
+    pub fn new(title: impl Into<String>) -> Result<Self, Error> {
+        let title = title.into();
+        if title.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "title" }.into());
+        }
+        Ok(Self {
+            title,
+            body: None,
+            assignees: Vec::new(),
+            milestone: None,
+            labels: Vec::new(),
+        })
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the users to assign.
+    pub fn assignees(mut self, assignees: &[&str]) -> Self {
+        self.assignees = assignees
+            .iter()
+            .map(|&assignee| assignee.to_owned())
+            .collect();
+        self
+    }
+
+    /// Sets the milestone, by its number.
+    pub fn milestone(mut self, milestone: usize) -> Self {
+        self.milestone = Some(milestone);
+        self
+    }
+
+    /// Sets the labels, by their names.
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.labels = labels.iter().map(|&label| label.to_owned()).collect();
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The changes to make with `Issues::update`. Fields that are not set are left as they are.
+pub struct UpdateIssue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+    /// `Some(None)` removes the milestone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Option<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+}
+
+impl UpdateIssue {
+    /// Creates a new `UpdateIssue` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title.
+    /// # Errors
+    /// Will error if the title is empty.
+    pub fn title(mut self, title: impl Into<String>) -> Result<Self, Error> {
+        let title = title.into();
+        if title.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "title" }.into());
+        }
+        self.title = Some(title);
+        Ok(self)
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Opens or closes the issue.
+    pub fn state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Replaces the assignees. An empty slice removes all of them.
+    pub fn assignees(mut self, assignees: &[&str]) -> Self {
+        self.assignees = Some(
+            assignees
+                .iter()
+                .map(|&assignee| assignee.to_owned())
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets the milestone by its number, or removes it with `None`.
+    pub fn milestone(mut self, milestone: Option<usize>) -> Self {
+        self.milestone = Some(milestone);
+        self
+    }
+
+    /// Replaces the labels. An empty slice removes all of them.
+    pub fn labels(mut self, labels: &[&str]) -> Self {
+        self.labels = Some(labels.iter().map(|&label| label.to_owned()).collect());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+/// The color of a label: 6 hex digits, without the `#`. It can only be made with `Color::new`, so it is always valid.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::issues::Color;
+/// assert_eq!(Color::new("#F29513").unwrap().as_str(), "F29513");
+/// assert!(Color::new("red").is_err());
+/// ```
+pub struct Color(String);
+
+impl Color {
+    /// Checks that `color` is 6 hex digits. A leading `#` is allowed, and stripped, since GitHub does not want it.
+    /// # Errors
+    /// Will error if the color is not 6 hex digits (with or without a `#`).
+    pub fn new(color: impl Into<String>) -> Result<Self, Error> {
+        let color = color.into();
+        let hex = color.strip_prefix('#').unwrap_or(&color);
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ValidationError::InvalidColor { color }.into());
+        }
+        Ok(Self(hex.to_owned()))
+    }
+
+    /// The hex digits, without the `#`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A label to create.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::issues::NewLabel;
+/// let label = NewLabel::new("bug", "#f29513")
+///     .unwrap()
+///     .description("Something isn't working");
+/// assert_eq!(label.color.as_str(), "f29513");
+/// assert!(NewLabel::new("bug", "red").is_err());
+/// ```
+pub struct NewLabel {
+    pub name: String,
+    pub color: Color,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl NewLabel {
+    /// Creates a new `NewLabel` with a name and a color.
+    /// # Errors
+    /// Will error if the name is empty, or if the color is not 6 hex digits (with or without a `#`).
+    pub fn new(name: impl Into<String>, color: impl Into<String>) -> Result<Self, Error> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "name" }.into());
+        }
+        Ok(Self {
+            name,
+            color: Color::new(color)?,
+            description: None,
+        })
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The changes to make with `Labels::update`. Fields that are not set are left as they are.
+pub struct UpdateLabel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl UpdateLabel {
+    /// Creates a new `UpdateLabel` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the label. Issues that have it keep it.
+    /// # Errors
+    /// Will error if the name is empty.
+    pub fn new_name(mut self, new_name: impl Into<String>) -> Result<Self, Error> {
+        let new_name = new_name.into();
+        if new_name.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "new_name" }.into());
+        }
+        self.new_name = Some(new_name);
+        Ok(self)
+    }
+
+    /// Sets the color.
+    /// # Errors
+    /// Will error if the color is not 6 hex digits (with or without a `#`).
+    pub fn color(mut self, color: impl Into<String>) -> Result<Self, Error> {
+        self.color = Some(Color::new(color)?);
+        Ok(self)
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MilestoneSort {
+    DueOn,
+    Completeness,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Filters and sorting for listing milestones. Pagination is set separately, with `PageParams`.
+pub struct MilestoneListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<MilestoneSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+}
+
+impl MilestoneListParams {
+    /// Creates a new `MilestoneListParams` with GitHub's defaults (open milestones, by due date).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists milestones in a state.
+    pub fn state(mut self, state: StateFilter) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets what to sort by.
+    pub fn sort(mut self, sort: MilestoneSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the sort direction.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A milestone to create.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::issues::NewMilestone;
+/// let milestone = NewMilestone::new("v1.0")
+///     .unwrap()
+///     .description("Tracking milestone for version 1.0");
+/// ```
+pub struct NewMilestone {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+impl NewMilestone {
+    /// Creates a new `NewMilestone` with a title.
+    /// # Errors
+    /// Will error if the title is empty.
+    pub fn new(title: impl Into<String>) -> Result<Self, Error> {
+        let title = title.into();
+        if title.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "title" }.into());
+        }
+        Ok(Self {
+            title,
+            state: None,
+            description: None,
+            due_on: None,
+        })
+    }
+
+    /// Sets the state. Milestones are open by default.
+    pub fn state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the due date.
+    pub fn due_on(mut self, due_on: DateTime<Utc>) -> Self {
+        self.due_on = Some(due_on);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The changes to make with `Milestones::update`. Fields that are not set are left as they are.
+pub struct UpdateMilestone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+impl UpdateMilestone {
+    /// Creates a new `UpdateMilestone` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title.
+    /// # Errors
+    /// Will error if the title is empty.
+    pub fn title(mut self, title: impl Into<String>) -> Result<Self, Error> {
+        let title = title.into();
+        if title.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "title" }.into());
+        }
+        self.title = Some(title);
+        Ok(self)
+    }
+
+    /// Opens or closes the milestone.
+    pub fn state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the due date.
+    pub fn due_on(mut self, due_on: DateTime<Utc>) -> Self {
+        self.due_on = Some(due_on);
+        self
+    }
+}
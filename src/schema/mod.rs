@@ -0,0 +1,412 @@
+pub mod checks;
+pub mod git;
+pub mod issues;
+pub mod orgs;
+pub mod pulls;
+pub mod repos;
+pub mod teams;
+
+pub mod users {
+    #[derive(Debug, serde::Deserialize)]
+    pub struct Plan {
+        pub name: String,
+        pub space: usize,
+        pub collaborators: usize,
+        pub private_repos: usize,
+    }
+
+    pub mod list {
+        use crate::{Error, ValidationError};
+
+        #[derive(serde::Deserialize, serde::Serialize, Debug)]
+        #[allow(dead_code)]
+        pub struct User {
+            pub login: String,
+            pub id: usize,
+            #[cfg(feature = "node_ids")]
+            node_id: String,
+            // url: String,
+            // html_url: String,
+            // followers_url: String,
+            // following_url: String,
+            // gists_url: String,
+            // starred_url: String,
+            // subscriptions_url: String,
+            // organizations_url: String,
+            // repos_url: String,
+            // events_url: String,
+            // received_events_url: String,
+            pub r#type: String,
+            pub site_admin: bool,
+        }
+
+        #[derive(serde::Serialize, Debug)]
+        #[non_exhaustive]
+        pub struct Params {
+            pub since: Option<usize>,
+            pub per_page: Option<usize>,
+        }
+
+        impl Params {
+            pub fn new(since: Option<usize>, per_page: Option<usize>) -> Result<Self, Error> {
+                if let Some(per_page) = per_page {
+                    if per_page > 100 {
+                        return Err(ValidationError::PerPageBiggerThan100.into());
+                    }
+                }
+
+                Ok(Self { since, per_page })
+            }
+        }
+    }
+
+    pub mod single {
+        use chrono::{DateTime, Utc};
+
+        #[derive(serde::Deserialize, Debug)]
+        pub struct User {
+            pub login: String,
+            pub id: usize,
+            // avatar_url: String,
+            pub gravatar_id: String,
+            // url: String,
+            // html_url: String,
+            // followers_url: String,
+            // following_url: String,
+            // gists_url: String,
+            // starred_url: String,
+            // subscriptions_url: String,
+            // organizations_url: String,
+            // repos_url: String,
+            // events_url: String,
+            // received_events_url: String,
+            pub r#type: String,
+            pub site_admin: bool,
+            pub name: Option<String>,
+            pub company: Option<String>,
+            pub blog: String,
+            pub location: String,
+            pub email: Option<String>,
+            pub hireable: Option<bool>,
+            pub bio: Option<String>,
+            pub twitter_username: Option<String>,
+            pub public_repos: usize,
+            pub public_gists: usize,
+            pub followers: usize,
+            pub following: usize,
+            pub created_at: DateTime<Utc>,
+            pub updated_at: DateTime<Utc>,
+            pub plan: Option<super::Plan>,
+        }
+    }
+
+    pub mod contextual_info {
+        #[derive(Debug, serde::Deserialize)]
+        pub struct User {
+            pub contexts: Vec<Context>,
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        pub struct Context {
+            pub message: String,
+            pub octicon: String,
+        }
+
+        #[derive(Debug, serde::Serialize)]
+        #[non_exhaustive]
+        pub struct Params {
+            subject_type: String,
+            subject_id: usize,
+        }
+
+        impl Params {
+            pub fn new(subject_type: String, subject_id: usize) -> Self {
+                Self {
+                    subject_type,
+                    subject_id,
+                }
+            }
+        }
+    }
+
+    pub mod current {
+        use chrono::{DateTime, Utc};
+
+        #[derive(serde::Deserialize, Debug)]
+        pub struct User {
+            pub login: String,
+            pub id: usize,
+            #[cfg(feature = "node_ids")]
+            pub node_id: String,
+            // avatar_url: String,
+            // gravatar_id: String,
+            // url: String,
+            // html_url: String,
+            // followers_url: String,
+            // following_url: String,
+            // gists_url: String,
+            // starred_url: String,
+            // subscriptions_url: String,
+            // organizations_url: String,
+            // repos_url: String,
+            // received_events_url: String,
+            pub r#type: String,
+            pub site_admin: bool,
+            pub name: Option<String>,
+            pub company: Option<String>,
+            pub blog: String,
+            pub location: Option<String>,
+            pub email: Option<String>,
+            pub hireable: Option<bool>,
+            pub bio: Option<String>,
+            pub twitter_username: Option<String>,
+            pub public_repos: usize,
+            pub public_gists: usize,
+            pub followers: usize,
+            pub following: usize,
+            pub created_at: DateTime<Utc>,
+            pub updated_at: DateTime<Utc>,
+            pub private_gists: Option<usize>,
+            pub total_private_repos: Option<usize>,
+            pub owned_private_repos: Option<usize>,
+            pub disk_usage: Option<usize>,
+            pub collaborators: Option<usize>,
+            pub two_factor_authentication: Option<bool>,
+            pub plan: Option<super::Plan>,
+        }
+
+        #[derive(serde::Serialize, Debug, Clone, Default, PartialEq, Eq)]
+        #[non_exhaustive]
+        /// The profile fields to change with `Users::update_current`. Fields that are not set are left as they are.
+        /// # Examples
+        /// ```rust
+        /// use use_github_api::schema::users::current::UpdateParams;
+        /// let params = UpdateParams::new().name("Octo Bot").hireable(false);
+        /// ```
+        pub struct UpdateParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub name: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub email: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub blog: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub twitter_username: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub company: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub location: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub hireable: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub bio: Option<String>,
+        }
+
+        impl UpdateParams {
+            /// Creates a new `UpdateParams` that does not change anything.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Sets the name.
+            pub fn name(mut self, name: impl Into<String>) -> Self {
+                self.name = Some(name.into());
+                self
+            }
+
+            /// Sets the public email. It has to be one of the user's verified emails.
+            pub fn email(mut self, email: impl Into<String>) -> Self {
+                self.email = Some(email.into());
+                self
+            }
+
+            /// Sets the blog (website) URL.
+            pub fn blog(mut self, blog: impl Into<String>) -> Self {
+                self.blog = Some(blog.into());
+                self
+            }
+
+            /// Sets the Twitter username, without the `@`.
+            pub fn twitter_username(mut self, twitter_username: impl Into<String>) -> Self {
+                self.twitter_username = Some(twitter_username.into());
+                self
+            }
+
+            /// Sets the company.
+            pub fn company(mut self, company: impl Into<String>) -> Self {
+                self.company = Some(company.into());
+                self
+            }
+
+            /// Sets the location.
+            pub fn location(mut self, location: impl Into<String>) -> Self {
+                self.location = Some(location.into());
+                self
+            }
+
+            /// Sets whether the user is available for hire.
+            pub fn hireable(mut self, hireable: bool) -> Self {
+                self.hireable = Some(hireable);
+                self
+            }
+
+            /// Sets the bio.
+            pub fn bio(mut self, bio: impl Into<String>) -> Self {
+                self.bio = Some(bio.into());
+                self
+            }
+        }
+    }
+
+    pub mod emails {
+        #[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+        #[serde(rename_all = "lowercase")]
+        /// Whether an email is shown on the user's profile.
+        pub enum Visibility {
+            Public,
+            Private,
+        }
+
+        #[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+        pub struct Email {
+            pub email: String,
+            pub primary: bool,
+            pub verified: bool,
+            /// Only set for the primary email.
+            pub visibility: Option<Visibility>,
+        }
+    }
+
+    pub mod keys {
+        use chrono::{DateTime, Utc};
+
+        #[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+        /// A public SSH key. The public listing for a user only has the `id` and `key`.
+        pub struct SshKey {
+            pub id: usize,
+            pub key: String,
+            pub title: Option<String>,
+            pub verified: Option<bool>,
+            pub read_only: Option<bool>,
+            pub created_at: Option<DateTime<Utc>>,
+        }
+
+        #[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+        pub struct GpgEmail {
+            pub email: String,
+            pub verified: bool,
+        }
+
+        #[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+        /// A GPG key. Subkeys have the same shape, but without emails or subkeys of their own.
+        pub struct GpgKey {
+            pub id: usize,
+            /// Only set for subkeys.
+            pub primary_key_id: Option<usize>,
+            pub key_id: String,
+            pub public_key: String,
+            #[serde(default)]
+            pub emails: Vec<GpgEmail>,
+            #[serde(default)]
+            pub subkeys: Vec<GpgKey>,
+            pub can_sign: bool,
+            pub can_encrypt_comms: bool,
+            pub can_encrypt_storage: bool,
+            pub can_certify: bool,
+            pub created_at: DateTime<Utc>,
+            /// `None` if the key does not expire.
+            pub expires_at: Option<DateTime<Utc>>,
+            pub raw_key: Option<String>,
+        }
+    }
+}
+
+pub mod rate_limit {
+    use chrono::{serde::ts_seconds, DateTime, Utc};
+
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+    pub struct RateLimit {
+        pub limit: usize,
+        pub remaining: usize,
+        #[serde(with = "ts_seconds")]
+        pub reset: DateTime<Utc>,
+        pub used: usize,
+        /// Only sent in the headers, not in the body of `GET /rate_limit`.
+        #[serde(default)]
+        pub resource: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Resources {
+        pub core: RateLimit,
+        pub search: RateLimit,
+        pub graphql: Option<RateLimit>,
+        pub integration_manifest: Option<RateLimit>,
+        pub source_import: Option<RateLimit>,
+        pub code_scanning_upload: Option<RateLimit>,
+        pub actions_runner_registration: Option<RateLimit>,
+        pub scim: Option<RateLimit>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Overview {
+        pub resources: Resources,
+        pub rate: RateLimit,
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+/// The author or committer of a Git commit or tag. This is not necessarily a GitHub user.
+/// When creating something, `date` can be left out, and GitHub uses the current time.
+pub struct GitUser {
+    pub name: String,
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GitUser {
+    /// Creates a `GitUser` without a date.
+    pub fn new(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            email: email.into(),
+            date: None,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct GitHubError {
+    pub message: String,
+    pub documentation_url: Option<String>,
+    /// Details on what went wrong, mostly sent along with `422 Unprocessable Entity`.
+    #[serde(default)]
+    pub errors: Vec<ErrorEntry>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+#[serde(untagged)]
+/// An entry in `GitHubError::errors`. Most endpoints send objects, but some only send a list of messages.
+pub enum ErrorEntry {
+    Detail(ErrorDetail),
+    Message(String),
+}
+
+impl ErrorEntry {
+    /// The message of the entry, if there is one.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            ErrorEntry::Detail(detail) => detail.message.as_deref(),
+            ErrorEntry::Message(message) => Some(message),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct ErrorDetail {
+    pub resource: Option<String>,
+    pub field: Option<String>,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
@@ -0,0 +1,365 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// The plan of an organization. Only sent to owners.
+pub struct Plan {
+    pub name: String,
+    pub space: usize,
+    pub private_repos: usize,
+    pub filled_seats: Option<usize>,
+    pub seats: Option<usize>,
+}
+
+pub mod list {
+    #[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+    pub struct Organization {
+        pub login: String,
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        // url: String,
+        // repos_url: String,
+        // events_url: String,
+        // hooks_url: String,
+        // issues_url: String,
+        // members_url: String,
+        // public_members_url: String,
+        // avatar_url: String,
+        pub description: Option<String>,
+    }
+}
+
+pub mod single {
+    use super::Plan;
+    use crate::schema::repos::Permission;
+    use chrono::{DateTime, Utc};
+
+    #[derive(serde::Deserialize, Debug)]
+    /// An organization. The fields from `total_private_repos` on are only sent to members, and some of them only to owners.
+    pub struct Organization {
+        pub login: String,
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        pub description: Option<String>,
+        pub name: Option<String>,
+        pub company: Option<String>,
+        pub blog: Option<String>,
+        pub location: Option<String>,
+        pub email: Option<String>,
+        pub twitter_username: Option<String>,
+        #[serde(default)]
+        pub is_verified: bool,
+        pub has_organization_projects: bool,
+        pub has_repository_projects: bool,
+        pub public_repos: usize,
+        pub public_gists: usize,
+        pub followers: usize,
+        pub following: usize,
+        pub html_url: String,
+        pub created_at: DateTime<Utc>,
+        pub updated_at: DateTime<Utc>,
+        pub r#type: String,
+        pub total_private_repos: Option<usize>,
+        pub owned_private_repos: Option<usize>,
+        pub private_gists: Option<usize>,
+        pub disk_usage: Option<usize>,
+        pub collaborators: Option<usize>,
+        pub billing_email: Option<String>,
+        pub plan: Option<Plan>,
+        pub default_repository_permission: Option<Permission>,
+        pub members_can_create_repositories: Option<bool>,
+        pub two_factor_requirement_enabled: Option<bool>,
+    }
+
+    #[derive(serde::Serialize, Debug, Clone, Default, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// The fields to change with `Orgs::update`. Fields that are not set are left as they are.
+    /// # Examples
+    /// ```rust
+    /// use use_github_api::schema::orgs::single::{DefaultPermission, UpdateParams};
+    /// let params = UpdateParams::new()
+    ///     .description("Making the world a better place")
+    ///     .default_repository_permission(DefaultPermission::Read);
+    /// ```
+    pub struct UpdateParams {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub billing_email: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub company: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub email: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub twitter_username: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub location: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub blog: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_repository_permission: Option<DefaultPermission>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub members_can_create_repositories: Option<bool>,
+    }
+
+    impl UpdateParams {
+        /// Creates a new `UpdateParams` that does not change anything.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Sets the billing email. It is not public.
+        pub fn billing_email(mut self, billing_email: impl Into<String>) -> Self {
+            self.billing_email = Some(billing_email.into());
+            self
+        }
+
+        /// Sets the company.
+        pub fn company(mut self, company: impl Into<String>) -> Self {
+            self.company = Some(company.into());
+            self
+        }
+
+        /// Sets the public email.
+        pub fn email(mut self, email: impl Into<String>) -> Self {
+            self.email = Some(email.into());
+            self
+        }
+
+        /// Sets the Twitter username, without the `@`.
+        pub fn twitter_username(mut self, twitter_username: impl Into<String>) -> Self {
+            self.twitter_username = Some(twitter_username.into());
+            self
+        }
+
+        /// Sets the location.
+        pub fn location(mut self, location: impl Into<String>) -> Self {
+            self.location = Some(location.into());
+            self
+        }
+
+        /// Sets the display name.
+        pub fn name(mut self, name: impl Into<String>) -> Self {
+            self.name = Some(name.into());
+            self
+        }
+
+        /// Sets the description.
+        pub fn description(mut self, description: impl Into<String>) -> Self {
+            self.description = Some(description.into());
+            self
+        }
+
+        /// Sets the blog (website) URL.
+        pub fn blog(mut self, blog: impl Into<String>) -> Self {
+            self.blog = Some(blog.into());
+            self
+        }
+
+        /// Sets the access that members have to every repository.
+        pub fn default_repository_permission(mut self, permission: DefaultPermission) -> Self {
+            self.default_repository_permission = Some(permission);
+            self
+        }
+
+        /// Sets whether members can create repositories.
+        pub fn members_can_create_repositories(
+            mut self,
+            members_can_create_repositories: bool,
+        ) -> Self {
+            self.members_can_create_repositories = Some(members_can_create_repositories);
+            self
+        }
+    }
+
+    #[derive(serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    /// The access to give members to every repository of an organization, unless they are given more.
+    pub enum DefaultPermission {
+        None,
+        Read,
+        Write,
+        Admin,
+    }
+}
+
+pub mod members {
+    use crate::schema::users::list::User;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Filter {
+        /// Only the members without two-factor authentication. Only owners can use this.
+        #[serde(rename = "2fa_disabled")]
+        TwoFactorDisabled,
+        #[serde(rename = "all")]
+        All,
+    }
+
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum RoleFilter {
+        All,
+        Admin,
+        Member,
+    }
+
+    #[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// Filters for listing members. Pagination is set separately, with `PageParams`.
+    pub struct ListParams {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub filter: Option<Filter>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub role: Option<RoleFilter>,
+    }
+
+    impl ListParams {
+        /// Creates a new `ListParams` that lists every member.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Sets the filter.
+        pub fn filter(mut self, filter: Filter) -> Self {
+            self.filter = Some(filter);
+            self
+        }
+
+        /// Only lists the members with a role.
+        pub fn role(mut self, role: RoleFilter) -> Self {
+            self.role = Some(role);
+            self
+        }
+    }
+
+    #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Role {
+        /// An owner.
+        Admin,
+        Member,
+        BillingManager,
+    }
+
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    /// The role to give a member. `BillingManager` is given with a separate API, so it cannot be set here.
+    pub enum NewRole {
+        /// An owner.
+        Admin,
+        Member,
+    }
+
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum State {
+        Active,
+        /// The user has been invited, and has not accepted yet.
+        Pending,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Membership {
+        pub state: State,
+        pub role: Role,
+        pub organization_url: String,
+        pub user: Option<User>,
+    }
+}
+
+pub mod invitations {
+    use crate::schema::users::list::User;
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    /// The role that an invited user gets. `HiringManager` and `Reinstate` are only sent by GitHub.
+    pub enum Role {
+        Admin,
+        DirectMember,
+        BillingManager,
+        HiringManager,
+        Reinstate,
+    }
+
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    /// The role to give a user with a new invitation.
+    pub enum NewRole {
+        Admin,
+        DirectMember,
+        BillingManager,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Invitation {
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        /// `None` for invitations by email.
+        pub login: Option<String>,
+        pub email: Option<String>,
+        pub role: Role,
+        pub created_at: DateTime<Utc>,
+        pub inviter: User,
+        pub team_count: usize,
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// An invitation to create, for a user or an email address.
+    /// # Examples
+    /// ```rust
+    /// use use_github_api::schema::orgs::invitations::{NewInvitation, NewRole};
+    /// let invitation = NewInvitation::email("octocat@github.com").role(NewRole::Admin).team_ids(vec![12]);
+    /// ```
+    pub struct NewInvitation {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub invitee_id: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub email: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub role: Option<NewRole>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub team_ids: Vec<usize>,
+    }
+
+    impl NewInvitation {
+        /// Creates an invitation for a user, by their id.
+        pub fn user(invitee_id: usize) -> Self {
+            Self {
+                invitee_id: Some(invitee_id),
+                email: None,
+                role: None,
+                team_ids: Vec::new(),
+            }
+        }
+
+        /// Creates an invitation for an email address, which does not need to have a GitHub account yet.
+        pub fn email(email: impl Into<String>) -> Self {
+            Self {
+                invitee_id: None,
+                email: Some(email.into()),
+                role: None,
+                team_ids: Vec::new(),
+            }
+        }
+
+        /// Sets the role. Defaults to `DirectMember`.
+        pub fn role(mut self, role: NewRole) -> Self {
+            self.role = Some(role);
+            self
+        }
+
+        /// Sets the teams that the user joins once they accept.
+        pub fn team_ids(mut self, team_ids: Vec<usize>) -> Self {
+            self.team_ids = team_ids;
+            self
+        }
+    }
+}
@@ -0,0 +1,590 @@
+use super::{
+    issues::{Label, LockReason, Milestone, State, StateFilter},
+    repos::Repository,
+    users::list,
+    GitUser,
+};
+use crate::{Error, ValidationError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub use super::repos::Direction;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Sort {
+    Created,
+    Updated,
+    Popularity,
+    /// Pull requests that have been open for a long time, and are still being worked on.
+    LongRunning,
+}
+
+#[derive(Deserialize, Debug)]
+/// One end of a pull request: the branch it comes from (`head`), or the one it goes into (`base`).
+pub struct Ref {
+    /// Like `octocat:new-topic`.
+    pub label: String,
+    pub r#ref: String,
+    pub sha: String,
+    pub user: Option<list::User>,
+    /// `None` if the repository was deleted.
+    pub repo: Option<Repository>,
+}
+
+#[derive(Deserialize, Debug)]
+/// A pull request. Some fields are only sent when fetching a single pull request, and are `None` in lists.
+pub struct PullRequest {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub number: usize,
+    pub state: State,
+    pub locked: bool,
+    pub active_lock_reason: Option<LockReason>,
+    pub title: String,
+    pub body: Option<String>,
+    pub user: list::User,
+    pub labels: Vec<Label>,
+    pub milestone: Option<Milestone>,
+    pub assignees: Vec<list::User>,
+    pub requested_reviewers: Vec<list::User>,
+    pub head: Ref,
+    pub base: Ref,
+    pub author_association: String,
+    pub draft: Option<bool>,
+    pub merge_commit_sha: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub merged_at: Option<DateTime<Utc>>,
+    pub merged: Option<bool>,
+    /// `None` while GitHub is still working out if the pull request can be merged.
+    pub mergeable: Option<bool>,
+    pub rebaseable: Option<bool>,
+    /// Like `clean`, `blocked`, `behind`, or `dirty`.
+    pub mergeable_state: Option<String>,
+    pub merged_by: Option<list::User>,
+    pub comments: Option<usize>,
+    pub review_comments: Option<usize>,
+    pub maintainer_can_modify: Option<bool>,
+    pub commits: Option<usize>,
+    pub additions: Option<usize>,
+    pub deletions: Option<usize>,
+    pub changed_files: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CommitParent {
+    pub sha: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// The Git side of a commit.
+pub struct CommitDetails {
+    pub author: Option<GitUser>,
+    pub committer: Option<GitUser>,
+    pub message: String,
+    pub comment_count: usize,
+}
+
+#[derive(Deserialize, Debug)]
+/// A commit on a pull request. `author` and `committer` are the GitHub users that the Git users are linked to, if any.
+pub struct Commit {
+    pub sha: String,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub commit: CommitDetails,
+    pub author: Option<list::User>,
+    pub committer: Option<list::User>,
+    pub parents: Vec<CommitParent>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStatus {
+    Added,
+    Removed,
+    Modified,
+    Renamed,
+    Copied,
+    Changed,
+    Unchanged,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+/// A file changed by a pull request.
+pub struct File {
+    pub sha: String,
+    pub filename: String,
+    pub status: FileStatus,
+    pub additions: usize,
+    pub deletions: usize,
+    pub changes: usize,
+    /// The unified diff of the file. `None` for binary files, and for diffs that are too big.
+    pub patch: Option<String>,
+    /// Only set for renamed files.
+    pub previous_filename: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MergeResult {
+    pub sha: String,
+    pub merged: bool,
+    pub message: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    Pending,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// What a review does when it is submitted.
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Review {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    /// `None` if the account was deleted.
+    pub user: Option<list::User>,
+    pub body: String,
+    pub state: ReviewState,
+    pub commit_id: String,
+    pub author_association: String,
+    /// `None` while the review is pending.
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+/// The side of a diff: `Left` is the old version (deletions), and `Right` is the new one (additions and context).
+pub enum Side {
+    Left,
+    Right,
+}
+
+#[derive(Deserialize, Debug)]
+/// A comment on a line of the diff of a pull request.
+pub struct ReviewComment {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub pull_request_review_id: Option<usize>,
+    pub in_reply_to_id: Option<usize>,
+    pub user: list::User,
+    pub body: String,
+    pub path: String,
+    pub diff_hunk: String,
+    pub commit_id: String,
+    pub original_commit_id: String,
+    /// `None` if the line is no longer in the diff.
+    pub line: Option<usize>,
+    pub original_line: Option<usize>,
+    pub side: Option<Side>,
+    /// Only set for comments on more than one line.
+    pub start_line: Option<usize>,
+    pub start_side: Option<Side>,
+    pub author_association: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Filters and sorting for listing pull requests. Pagination is set separately, with `PageParams`.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::{issues::StateFilter, pulls::ListParams};
+/// let params = ListParams::new().state(StateFilter::All).base("main");
+/// ```
+pub struct ListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateFilter>,
+    /// Like `octocat:new-topic`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+}
+
+impl ListParams {
+    /// Creates a new `ListParams` with GitHub's defaults (open pull requests, newest first).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists pull requests in a state.
+    pub fn state(mut self, state: StateFilter) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Only lists pull requests from a branch, written as `user:branch` (or `org:branch`).
+    pub fn head(mut self, head: impl Into<String>) -> Self {
+        self.head = Some(head.into());
+        self
+    }
+
+    /// Only lists pull requests into a branch.
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    /// Sets what to sort by.
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the sort direction.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A pull request to create.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::pulls::NewPull;
+/// let pull = NewPull::new("Amazing new feature", "octocat:new-feature", "main")
+///     .unwrap()
+///     .draft(true);
+/// ```
+pub struct NewPull {
+    pub title: String,
+    /// The branch with the changes. For branches in forks, it is `user:branch`.
+    pub head: String,
+    pub base: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+}
+
+impl NewPull {
+    /// Creates a new `NewPull` from `head` into `base`.
+    /// # Errors
+    /// Will error if the title, `head`, or `base` is empty.
+    pub fn new(
+        title: impl Into<String>,
+        head: impl Into<String>,
+        base: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let (title, head, base) = (title.into(), head.into(), base.into());
+        for &(field, value) in &[("title", &title), ("head", &head), ("base", &base)] {
+            if value.trim().is_empty() {
+                return Err(ValidationError::Empty { field }.into());
+            }
+        }
+        Ok(Self {
+            title,
+            head,
+            base,
+            body: None,
+            draft: None,
+            maintainer_can_modify: None,
+        })
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets whether the pull request is a draft.
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = Some(draft);
+        self
+    }
+
+    /// Sets whether maintainers of `base` can push to `head`.
+    pub fn maintainer_can_modify(mut self, maintainer_can_modify: bool) -> Self {
+        self.maintainer_can_modify = Some(maintainer_can_modify);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The changes to make with `Pulls::update`. Fields that are not set are left as they are.
+pub struct UpdatePull {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_can_modify: Option<bool>,
+}
+
+impl UpdatePull {
+    /// Creates a new `UpdatePull` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title.
+    /// # Errors
+    /// Will error if the title is empty.
+    pub fn title(mut self, title: impl Into<String>) -> Result<Self, Error> {
+        let title = title.into();
+        if title.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "title" }.into());
+        }
+        self.title = Some(title);
+        Ok(self)
+    }
+
+    /// Sets the body.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Closes or reopens the pull request.
+    pub fn state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Changes the branch that the pull request goes into.
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    /// Sets whether maintainers of `base` can push to `head`.
+    pub fn maintainer_can_modify(mut self, maintainer_can_modify: bool) -> Self {
+        self.maintainer_can_modify = Some(maintainer_can_modify);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeMethod {
+    Merge,
+    Squash,
+    Rebase,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// How to merge a pull request with `Pulls::merge`.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::pulls::{MergeMethod, MergeParams};
+/// // Only merges if nobody has pushed since the checks ran on this commit.
+/// let params = MergeParams::new()
+///     .method(MergeMethod::Squash)
+///     .sha("6dcb09b5b57875f334f61aebed695e2e4193db5e");
+/// ```
+pub struct MergeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_message: Option<String>,
+    /// The commit that `head` has to be at for the merge to happen.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(rename = "merge_method", skip_serializing_if = "Option::is_none")]
+    pub method: Option<MergeMethod>,
+}
+
+impl MergeParams {
+    /// Creates a new `MergeParams` with the repository's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the merge (or squash) commit.
+    pub fn commit_title(mut self, commit_title: impl Into<String>) -> Self {
+        self.commit_title = Some(commit_title.into());
+        self
+    }
+
+    /// Sets the extra message of the merge (or squash) commit.
+    pub fn commit_message(mut self, commit_message: impl Into<String>) -> Self {
+        self.commit_message = Some(commit_message.into());
+        self
+    }
+
+    /// Only merges if `head` is at this commit.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    /// Sets the merge method. It has to be allowed in the repository's settings.
+    pub fn method(mut self, method: MergeMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A comment that is part of a `NewReview`.
+pub struct DraftReviewComment {
+    pub path: String,
+    pub body: String,
+    /// A line of the new version of the file.
+    pub line: usize,
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// A review to create. Without an `event`, the review stays pending until it is submitted.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::pulls::{NewReview, ReviewEvent};
+/// let review = NewReview::new()
+///     .event(ReviewEvent::RequestChanges)
+///     .body("Almost there!")
+///     .comment("src/lib.rs", 12, "This can panic.");
+/// ```
+pub struct NewReview {
+    /// Defaults to the latest commit of the pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<ReviewEvent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<DraftReviewComment>,
+}
+
+impl NewReview {
+    /// Creates a new, empty `NewReview`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reviews a commit other than the latest one.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// Sets the body. It is required for `RequestChanges` and `Comment`.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Submits the review right away.
+    pub fn event(mut self, event: ReviewEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Adds a comment on a line of the new version of a file.
+    pub fn comment(
+        mut self,
+        path: impl Into<String>,
+        line: usize,
+        body: impl Into<String>,
+    ) -> Self {
+        self.comments.push(DraftReviewComment {
+            path: path.into(),
+            body: body.into(),
+            line,
+        });
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A review comment to create on its own, outside of a review.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::pulls::{NewReviewComment, Side};
+/// let comment = NewReviewComment::new(
+///     "Great stuff!",
+///     "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+///     "file1.txt",
+///     2,
+/// )
+/// .unwrap()
+/// .side(Side::Right);
+/// ```
+pub struct NewReviewComment {
+    pub body: String,
+    pub commit_id: String,
+    pub path: String,
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_side: Option<Side>,
+}
+
+impl NewReviewComment {
+    /// Creates a new `NewReviewComment` on `line` of `path`, as it is at `commit_id`.
+    /// # Errors
+    /// Will error if the body is empty.
+    pub fn new(
+        body: impl Into<String>,
+        commit_id: impl Into<String>,
+        path: impl Into<String>,
+        line: usize,
+    ) -> Result<Self, Error> {
+        let body = body.into();
+        if body.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "body" }.into());
+        }
+        Ok(Self {
+            body,
+            commit_id: commit_id.into(),
+            path: path.into(),
+            line,
+            side: None,
+            start_line: None,
+            start_side: None,
+        })
+    }
+
+    /// Sets the side of the diff. Defaults to `Right`.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Makes the comment span from `start_line` to `line`.
+    pub fn start_line(mut self, start_line: usize, start_side: Side) -> Self {
+        self.start_line = Some(start_line);
+        self.start_side = Some(start_side);
+        self
+    }
+}
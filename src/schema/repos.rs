@@ -0,0 +1,824 @@
+use super::users::list;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Permissions {
+    pub admin: bool,
+    pub maintain: Option<bool>,
+    pub push: bool,
+    pub triage: Option<bool>,
+    pub pull: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+/// A level of access to a repository, from the least to the most.
+/// GitHub calls `Read` and `Write` `pull` and `push` in some places, and both names are understood.
+pub enum Permission {
+    /// No access. Only sent for the default access of the members of an organization, and for users without access.
+    None,
+    /// Read and clone.
+    #[serde(alias = "pull")]
+    Read,
+    /// Read, and manage issues and pull requests.
+    Triage,
+    /// Read and write.
+    #[serde(alias = "push")]
+    Write,
+    /// Write, and manage the repository without access to sensitive or destructive actions.
+    Maintain,
+    Admin,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+/// The access to give a team or a collaborator to a repository.
+pub enum NewPermission {
+    /// Read and clone.
+    #[serde(rename = "pull")]
+    Read,
+    /// Read, and manage issues and pull requests.
+    Triage,
+    /// Read and write.
+    #[serde(rename = "push")]
+    Write,
+    /// Write, and manage the repository without access to sensitive or destructive actions.
+    Maintain,
+    Admin,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct License {
+    pub key: String,
+    pub name: String,
+    pub spdx_id: Option<String>,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Repository {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub name: String,
+    pub full_name: String,
+    pub owner: list::User,
+    pub private: bool,
+    // html_url: String,
+    pub description: Option<String>,
+    pub fork: bool,
+    // url: String,
+    // (and a lot more API URLs)
+    pub clone_url: String,
+    pub ssh_url: String,
+    pub homepage: Option<String>,
+    pub language: Option<String>,
+    pub forks_count: usize,
+    pub stargazers_count: usize,
+    pub watchers_count: usize,
+    /// In kilobytes.
+    pub size: usize,
+    pub default_branch: String,
+    pub open_issues_count: usize,
+    pub is_template: Option<bool>,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    pub has_issues: bool,
+    pub has_projects: bool,
+    pub has_wiki: bool,
+    pub has_pages: bool,
+    pub has_downloads: bool,
+    pub archived: bool,
+    pub disabled: bool,
+    pub visibility: Option<String>,
+    pub pushed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Only set when authenticated.
+    pub permissions: Option<Permissions>,
+    pub allow_squash_merge: Option<bool>,
+    pub allow_merge_commit: Option<bool>,
+    pub allow_rebase_merge: Option<bool>,
+    pub delete_branch_on_merge: Option<bool>,
+    pub license: Option<License>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Contributor {
+    pub login: String,
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub r#type: String,
+    pub site_admin: bool,
+    pub contributions: usize,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TagCommit {
+    pub sha: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub name: String,
+    pub commit: TagCommit,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+}
+
+/// Bytes of code per language.
+pub type Languages = BTreeMap<String, usize>;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+/// Which repositories to list. Not every endpoint supports every type: `Owner` is only for users, and `Internal` is only for organizations.
+pub enum Type {
+    All,
+    Owner,
+    Public,
+    Private,
+    Forks,
+    Sources,
+    Member,
+    Internal,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Sort {
+    Created,
+    Updated,
+    Pushed,
+    FullName,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Asc,
+    Desc,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Filters and sorting for listing repositories. Pagination is set separately, with `PageParams`.
+pub struct ListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Type>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<Direction>,
+}
+
+impl ListParams {
+    /// Creates a new `ListParams` with GitHub's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists repositories of a type.
+    pub fn r#type(mut self, r#type: Type) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    /// Sets what to sort by.
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the sort direction.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A repository to create. Only the name is required.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::repos::NewRepo;
+/// let repo = NewRepo::new("config").private(true).auto_init(true);
+/// ```
+pub struct NewRepo {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitignore_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_template: Option<String>,
+}
+
+impl NewRepo {
+    /// Creates a new `NewRepo` with a name, leaving everything else to GitHub's defaults.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            homepage: None,
+            private: None,
+            has_issues: None,
+            has_projects: None,
+            has_wiki: None,
+            is_template: None,
+            auto_init: None,
+            gitignore_template: None,
+            license_template: None,
+        }
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the homepage URL.
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Sets whether the repository is private.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    /// Turns issues on or off.
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.has_issues = Some(has_issues);
+        self
+    }
+
+    /// Turns projects on or off.
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.has_projects = Some(has_projects);
+        self
+    }
+
+    /// Turns the wiki on or off.
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.has_wiki = Some(has_wiki);
+        self
+    }
+
+    /// Sets whether the repository can be used as a template.
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.is_template = Some(is_template);
+        self
+    }
+
+    /// Creates an initial commit with an empty README.
+    pub fn auto_init(mut self, auto_init: bool) -> Self {
+        self.auto_init = Some(auto_init);
+        self
+    }
+
+    /// Adds a `.gitignore` from a template, like `"Rust"`.
+    pub fn gitignore_template(mut self, gitignore_template: impl Into<String>) -> Self {
+        self.gitignore_template = Some(gitignore_template.into());
+        self
+    }
+
+    /// Adds a license from a template, like `"mit"`.
+    pub fn license_template(mut self, license_template: impl Into<String>) -> Self {
+        self.license_template = Some(license_template.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The repository settings to change with `Repos::update`. Fields that are not set are left as they are.
+pub struct UpdateRepo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl UpdateRepo {
+    /// Creates a new `UpdateRepo` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the repository.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the homepage URL.
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Sets whether the repository is private.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    /// Turns issues on or off.
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.has_issues = Some(has_issues);
+        self
+    }
+
+    /// Turns projects on or off.
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.has_projects = Some(has_projects);
+        self
+    }
+
+    /// Turns the wiki on or off.
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.has_wiki = Some(has_wiki);
+        self
+    }
+
+    /// Sets whether the repository can be used as a template.
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.is_template = Some(is_template);
+        self
+    }
+
+    /// Sets the default branch.
+    pub fn default_branch(mut self, default_branch: impl Into<String>) -> Self {
+        self.default_branch = Some(default_branch.into());
+        self
+    }
+
+    /// Allows or disallows squash merging.
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    /// Allows or disallows merge commits.
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    /// Allows or disallows rebase merging.
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    /// Sets whether head branches are deleted once their pull request is merged.
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    /// Archives the repository. Archived repositories cannot be unarchived through the API.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+pub mod contents {
+    use crate::schema::GitUser;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+
+    /// GitHub wraps base64 content at 60 characters, so the line breaks have to go before decoding.
+    pub(crate) fn decode<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let content = String::deserialize(deserializer)?;
+        let content: String = content.split_whitespace().collect();
+        STANDARD.decode(content).map_err(de::Error::custom)
+    }
+
+    pub(crate) fn encode<S: Serializer>(content: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(content))
+    }
+
+    #[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum EntryType {
+        File,
+        Dir,
+        Symlink,
+        Submodule,
+    }
+
+    #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+    /// An item in a directory, without its content.
+    pub struct Entry {
+        pub r#type: EntryType,
+        pub size: usize,
+        pub name: String,
+        pub path: String,
+        pub sha: String,
+        // url: String,
+        // git_url: String,
+        // html_url: String,
+        /// Only set for files.
+        pub download_url: Option<String>,
+    }
+
+    #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+    /// A file, with its content already decoded from base64.
+    pub struct File {
+        pub size: usize,
+        pub name: String,
+        pub path: String,
+        pub sha: String,
+        /// Empty for files over 1 MB, which GitHub does not send the content of. Use `download_url` or a blob for those.
+        #[serde(deserialize_with = "decode")]
+        pub content: Vec<u8>,
+        pub download_url: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    /// What is at a path: a file (with its content), a directory (with its entries), or a symlink or submodule.
+    pub enum Content {
+        File(File),
+        Directory(Vec<Entry>),
+        Other(Entry),
+    }
+
+    impl<'de> Deserialize<'de> for Content {
+        /// A directory is a list of entries, and everything else goes by its `type`, so a file that fails to decode is an error instead of an `Other`.
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let value = Value::deserialize(deserializer)?;
+            let content = match &value {
+                Value::Array(_) => serde_json::from_value(value).map(Content::Directory),
+                Value::Object(object)
+                    if object.get("type").and_then(Value::as_str) == Some("file") =>
+                {
+                    serde_json::from_value(value).map(Content::File)
+                }
+                _ => serde_json::from_value(value).map(Content::Other),
+            };
+            content.map_err(de::Error::custom)
+        }
+    }
+
+    #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+    pub struct CommitSummary {
+        pub sha: String,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        pub message: String,
+        pub author: GitUser,
+        pub committer: GitUser,
+    }
+
+    #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+    /// The result of changing a file: the file as it is now (`None` after deleting it), and the commit that changed it.
+    pub struct FileCommit {
+        pub content: Option<Entry>,
+        pub commit: CommitSummary,
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// A file to create or update, with `Contents::put`. The content is base64 encoded when it is sent.
+    /// # Examples
+    /// ```rust
+    /// use use_github_api::schema::repos::contents::FileUpdate;
+    /// let update = FileUpdate::new("Bump the version", "version: 2\n").sha("95b966ae1c166bd92f8ae7d1c313e738c731dfc3");
+    /// ```
+    pub struct FileUpdate {
+        pub message: String,
+        #[serde(serialize_with = "encode")]
+        pub content: Vec<u8>,
+        /// The blob SHA of the file being replaced. Required for updates, and has to be left out for new files.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub sha: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub branch: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub committer: Option<GitUser>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub author: Option<GitUser>,
+    }
+
+    impl FileUpdate {
+        /// Creates a new `FileUpdate` with a commit message and the new content of the file.
+        pub fn new(message: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+            Self {
+                message: message.into(),
+                content: content.into(),
+                sha: None,
+                branch: None,
+                committer: None,
+                author: None,
+            }
+        }
+
+        /// Sets the blob SHA of the file being replaced, which makes this an update.
+        pub fn sha(mut self, sha: impl Into<String>) -> Self {
+            self.sha = Some(sha.into());
+            self
+        }
+
+        /// Sets the branch to commit to. The default branch is used otherwise.
+        pub fn branch(mut self, branch: impl Into<String>) -> Self {
+            self.branch = Some(branch.into());
+            self
+        }
+
+        /// Sets the committer. The current authenticated user is used otherwise.
+        pub fn committer(mut self, committer: GitUser) -> Self {
+            self.committer = Some(committer);
+            self
+        }
+
+        /// Sets the author. The committer is used otherwise.
+        pub fn author(mut self, author: GitUser) -> Self {
+            self.author = Some(author);
+            self
+        }
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// A file to delete, with `Contents::delete`.
+    pub struct FileDelete {
+        pub message: String,
+        /// The blob SHA of the file being deleted.
+        pub sha: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub branch: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub committer: Option<GitUser>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub author: Option<GitUser>,
+    }
+
+    impl FileDelete {
+        /// Creates a new `FileDelete` with a commit message and the blob SHA of the file.
+        pub fn new(message: impl Into<String>, sha: impl Into<String>) -> Self {
+            Self {
+                message: message.into(),
+                sha: sha.into(),
+                branch: None,
+                committer: None,
+                author: None,
+            }
+        }
+
+        /// Sets the branch to commit to. The default branch is used otherwise.
+        pub fn branch(mut self, branch: impl Into<String>) -> Self {
+            self.branch = Some(branch.into());
+            self
+        }
+
+        /// Sets the committer. The current authenticated user is used otherwise.
+        pub fn committer(mut self, committer: GitUser) -> Self {
+            self.committer = Some(committer);
+            self
+        }
+
+        /// Sets the author. The committer is used otherwise.
+        pub fn author(mut self, author: GitUser) -> Self {
+            self.author = Some(author);
+            self
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ArchiveFormat {
+        Tarball,
+        Zipball,
+    }
+
+    impl ArchiveFormat {
+        pub(crate) fn as_str(self) -> &'static str {
+            match self {
+                ArchiveFormat::Tarball => "tarball",
+                ArchiveFormat::Zipball => "zipball",
+            }
+        }
+    }
+}
+
+pub mod statuses {
+    use crate::schema::users::list;
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum State {
+        Error,
+        Failure,
+        Pending,
+        Success,
+    }
+
+    #[derive(Deserialize, Debug)]
+    pub struct Status {
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        pub state: State,
+        pub description: Option<String>,
+        pub target_url: Option<String>,
+        pub context: String,
+        pub creator: Option<list::User>,
+        pub created_at: DateTime<Utc>,
+        pub updated_at: DateTime<Utc>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    /// The latest status of every context on a commit, and a state for all of them together:
+    /// `failure` if any of them is `error` or `failure`, `pending` if there are none or any of them is `pending`, and `success` otherwise.
+    pub struct CombinedStatus {
+        pub state: State,
+        pub sha: String,
+        pub total_count: usize,
+        pub statuses: Vec<Status>,
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    #[non_exhaustive]
+    /// A status to create.
+    /// # Examples
+    /// ```rust
+    /// use use_github_api::schema::repos::statuses::{NewStatus, State};
+    /// let status = NewStatus::new(State::Success)
+    ///     .context("ci/build")
+    ///     .description("The build succeeded!")
+    ///     .target_url("https://ci.example.com/builds/1000");
+    /// ```
+    pub struct NewStatus {
+        pub state: State,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub target_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Defaults to `default`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub context: Option<String>,
+    }
+
+    impl NewStatus {
+        /// Creates a new `NewStatus` with a state.
+        pub fn new(state: State) -> Self {
+            Self {
+                state,
+                target_url: None,
+                description: None,
+                context: None,
+            }
+        }
+
+        /// Sets the URL that the status links to, like the page of a CI build.
+        pub fn target_url(mut self, target_url: impl Into<String>) -> Self {
+            self.target_url = Some(target_url.into());
+            self
+        }
+
+        /// Sets the description.
+        pub fn description(mut self, description: impl Into<String>) -> Self {
+            self.description = Some(description.into());
+            self
+        }
+
+        /// Sets the context, which tells statuses from different systems apart.
+        pub fn context(mut self, context: impl Into<String>) -> Self {
+            self.context = Some(context.into());
+            self
+        }
+    }
+}
+
+pub mod collaborators {
+    use super::{Permission, Permissions};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Affiliation {
+        /// Only the collaborators that are not members of the organization that owns the repository.
+        Outside,
+        /// Only the collaborators with access of their own, not through an organization or team.
+        Direct,
+        All,
+    }
+
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    pub struct Collaborator {
+        pub login: String,
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        node_id: String,
+        pub r#type: String,
+        pub site_admin: bool,
+        pub permissions: Option<Permissions>,
+        /// Can be the name of a custom role.
+        pub role_name: Option<String>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    /// The access that a user has to a repository, through any collaborator, team, or organization membership.
+    pub struct PermissionLevel {
+        /// `Write` for `maintain` and `triage` too. `role_name` tells them apart.
+        pub permission: Permission,
+        pub role_name: String,
+        pub user: Option<Collaborator>,
+    }
+}
+
+pub mod invitations {
+    use super::Permission;
+    use crate::schema::users::list;
+    use chrono::{DateTime, Utc};
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    /// The repository of an invitation. GitHub only sends some of the fields of a `Repository` here.
+    pub struct Repository {
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        pub name: String,
+        pub full_name: String,
+        pub owner: list::User,
+        pub private: bool,
+        pub html_url: String,
+        pub description: Option<String>,
+        pub fork: bool,
+    }
+
+    #[derive(Deserialize, Debug)]
+    /// An invitation to collaborate on a repository.
+    pub struct Invitation {
+        pub id: usize,
+        #[cfg(feature = "node_ids")]
+        pub node_id: String,
+        pub repository: Repository,
+        pub invitee: Option<list::User>,
+        pub inviter: Option<list::User>,
+        pub permissions: Permission,
+        pub created_at: DateTime<Utc>,
+        /// Invitations expire after 7 days.
+        #[serde(default)]
+        pub expired: bool,
+        pub html_url: String,
+    }
+}
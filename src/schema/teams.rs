@@ -0,0 +1,216 @@
+use crate::{schema::orgs::members::State, Error, ValidationError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Privacy {
+    /// Only visible to owners and members of the team.
+    Secret,
+    /// Visible to every member of the organization. Teams with a parent or children have to be closed.
+    Closed,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Team {
+    pub id: usize,
+    #[cfg(feature = "node_ids")]
+    pub node_id: String,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub privacy: Option<Privacy>,
+    /// The permission that new repositories of the team get, which is deprecated.
+    pub permission: String,
+    pub html_url: String,
+    /// Not set for the parent of a team.
+    #[serde(default)]
+    pub parent: Option<Box<Team>>,
+    /// Only sent when a single team is fetched.
+    pub members_count: Option<usize>,
+    pub repos_count: Option<usize>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// A team to create.
+/// # Examples
+/// ```rust
+/// use use_github_api::schema::teams::{NewTeam, Privacy};
+/// let team = NewTeam::new("Justice League")
+///     .unwrap()
+///     .description("A great team")
+///     .privacy(Privacy::Closed)
+///     .maintainers(vec!["octocat".to_owned()]);
+/// ```
+pub struct NewTeam {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Logins of organization members that become maintainers of the team.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub maintainers: Vec<String>,
+    /// Full names (`owner/repo`) of repositories that the team gets access to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub repo_names: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<Privacy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_team_id: Option<usize>,
+}
+
+impl NewTeam {
+    /// Creates a new `NewTeam` with a name.
+    /// # Errors
+    /// Will error if the name is empty.
+    pub fn new(name: impl Into<String>) -> Result<Self, Error> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "name" }.into());
+        }
+        Ok(Self {
+            name,
+            description: None,
+            maintainers: Vec::new(),
+            repo_names: Vec::new(),
+            privacy: None,
+            parent_team_id: None,
+        })
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the maintainers.
+    pub fn maintainers(mut self, maintainers: Vec<String>) -> Self {
+        self.maintainers = maintainers;
+        self
+    }
+
+    /// Sets the repositories that the team gets access to.
+    pub fn repo_names(mut self, repo_names: Vec<String>) -> Self {
+        self.repo_names = repo_names;
+        self
+    }
+
+    /// Sets the privacy. Defaults to `Secret`, or to `Closed` for nested teams.
+    pub fn privacy(mut self, privacy: Privacy) -> Self {
+        self.privacy = Some(privacy);
+        self
+    }
+
+    /// Nests the team under another one.
+    pub fn parent_team_id(mut self, parent_team_id: usize) -> Self {
+        self.parent_team_id = Some(parent_team_id);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// The changes to make with `Teams::update`. Fields that are not set are left as they are.
+pub struct UpdateTeam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy: Option<Privacy>,
+    /// `Some(None)` removes the parent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_team_id: Option<Option<usize>>,
+}
+
+impl UpdateTeam {
+    /// Creates a new `UpdateTeam` that does not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the team. This changes its slug too.
+    /// # Errors
+    /// Will error if the name is empty.
+    pub fn name(mut self, name: impl Into<String>) -> Result<Self, Error> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(ValidationError::Empty { field: "name" }.into());
+        }
+        self.name = Some(name);
+        Ok(self)
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the privacy.
+    pub fn privacy(mut self, privacy: Privacy) -> Self {
+        self.privacy = Some(privacy);
+        self
+    }
+
+    /// Moves the team under another one, or to the top level with `None`.
+    pub fn parent_team_id(mut self, parent_team_id: Option<usize>) -> Self {
+        self.parent_team_id = Some(parent_team_id);
+        self
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Member,
+    /// Can add and remove members, and change the name and description of the team.
+    Maintainer,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RoleFilter {
+    Member,
+    Maintainer,
+    All,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Membership {
+    pub url: String,
+    pub role: Role,
+    /// `Pending` until the user accepts the invitation to the organization.
+    pub state: State,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+/// A group of an identity provider, which team members can be synced from.
+pub struct Group {
+    pub group_id: String,
+    pub group_name: String,
+    pub group_description: String,
+}
+
+impl Group {
+    /// Creates a `Group`. The id, name, and description have to match the ones that the identity provider has.
+    pub fn new(
+        group_id: impl Into<String>,
+        group_name: impl Into<String>,
+        group_description: impl Into<String>,
+    ) -> Self {
+        Self {
+            group_id: group_id.into(),
+            group_name: group_name.into(),
+            group_description: group_description.into(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct GroupMapping {
+    pub groups: Vec<Group>,
+}
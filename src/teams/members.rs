@@ -0,0 +1,168 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::teams::Role;
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::{
+        teams::{Membership, RoleFilter},
+        users::list::User,
+    },
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct RoleParams {
+    role: RoleFilter,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct SetRole {
+    role: Role,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Members of teams. The members of nested teams are members of their parents too.
+pub struct Members<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Members<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Members { client }
+    }
+
+    /// Fetches a page of the members of a team, including the members of nested teams.
+    pub async fn list(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: Option<RoleFilter>,
+        page: Option<PageParams>,
+    ) -> Result<Vec<User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/orgs/{}/teams/{}/members", org, team_slug),
+                    )
+                    .query(&role.map(|role| RoleParams { role }))?
+                    .query(&page)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every member of a team, page by page.
+    pub fn list_stream(
+        &self,
+        org: &'a str,
+        team_slug: &'a str,
+        role: Option<RoleFilter>,
+        page: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<User, Error>> + 'a {
+        let members = *self;
+        paginate_pages(page, limits, move |page| async move {
+            members.list(org, team_slug, role, Some(page)).await
+        })
+    }
+
+    /// Fetches the membership of a user in a team.
+    /// # Errors
+    /// Will error with `Error::NotFound` if the user is not a member of the team.
+    pub async fn get(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> Result<Membership, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/orgs/{}/teams/{}/memberships/{}", org, team_slug, username),
+            ))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds a user to a team, or changes their role. Users that are not members of the organization are invited to it,
+    /// and the membership is pending until they accept.
+    pub async fn set(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+        role: Role,
+    ) -> Result<Membership, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/orgs/{}/teams/{}/memberships/{}", org, team_slug, username),
+                    )
+                    .json(&SetRole { role })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Removes a user from a team. They stay a member of the organization.
+    /// # Errors
+    /// Will error with `Error::Forbidden` if the team's members are synced from an identity provider.
+    pub async fn remove(&self, org: &str, team_slug: &str, username: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/teams/{}/memberships/{}", org, team_slug, username),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::{
+            orgs::members::State,
+            teams::{Role, RoleFilter},
+        },
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn memberships_work() {
+        let client = cassette_client("teams/members");
+        let members = client.teams().members();
+        let membership = members
+            .set("github", "justice-league", "octocat", Role::Maintainer)
+            .await
+            .unwrap();
+        assert_eq!(membership.state, State::Active);
+        let maintainers = members
+            .list(
+                "github",
+                "justice-league",
+                Some(RoleFilter::Maintainer),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(maintainers[0].login, "octocat");
+        members
+            .remove("github", "justice-league", "octocat")
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::teams::{NewTeam, UpdateTeam};
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::teams::Team,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+pub mod members;
+pub mod repos;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod sync;
+use members::Members;
+use repos::Repos;
+#[cfg(any(feature = "auth", doc))]
+use sync::TeamSync;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Teams of organizations, by their slugs. Most of this only works for members of the organization.
+pub struct Teams<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Teams<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Teams { client }
+    }
+
+    /// Members of teams.
+    pub fn members(&self) -> Members<'a> {
+        Members::new(self.client)
+    }
+
+    /// The repositories that teams have access to.
+    pub fn repos(&self) -> Repos<'a> {
+        Repos::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Syncing the members of teams from the groups of an identity provider. Needs GitHub Enterprise Cloud.
+    pub fn sync(&self) -> TeamSync<'a> {
+        TeamSync::new(self.client)
+    }
+
+    /// Fetches a page of the teams of an organization that the current user can see.
+    pub async fn list(&self, org: &str, params: Option<PageParams>) -> Result<Vec<Team>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/teams", org))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every team of an organization that the current user can see, page by page.
+    pub fn list_stream(
+        &self,
+        org: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Team, Error>> + 'a {
+        let teams = *self;
+        paginate_pages(params, limits, move |params| async move {
+            teams.list(org, Some(params)).await
+        })
+    }
+
+    /// Fetches a team by its slug.
+    pub async fn get(&self, org: &str, team_slug: &str) -> Result<Team, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/teams/{}", org, team_slug)),
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Creates a team. The current authenticated user becomes a maintainer of it.
+    /// # Errors
+    /// Will error with `Error::Unprocessable` if there is already a team with the name.
+    pub async fn create(&self, org: &str, team: &NewTeam) -> Result<Team, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, &format!("/orgs/{}/teams", org))
+                    .json(team)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Changes a team, and returns the updated team.
+    pub async fn update(
+        &self,
+        org: &str,
+        team_slug: &str,
+        changes: &UpdateTeam,
+    ) -> Result<Team, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PATCH, &format!("/orgs/{}/teams/{}", org, team_slug))
+                    .json(changes)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes a team, and every team nested under it.
+    pub async fn delete(&self, org: &str, team_slug: &str) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/teams/{}", org, team_slug),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the teams nested directly under a team.
+    pub async fn children(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<Team>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/orgs/{}/teams/{}/teams", org, team_slug),
+                    )
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every team nested directly under a team, page by page.
+    pub fn children_stream(
+        &self,
+        org: &'a str,
+        team_slug: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Team, Error>> + 'a {
+        let teams = *self;
+        paginate_pages(params, limits, move |params| async move {
+            teams.children(org, team_slug, Some(params)).await
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{
+        schema::teams::{NewTeam, Privacy},
+        test_utils::cassette_client,
+    };
+
+    #[tokio::test]
+    async fn teams_can_be_nested() {
+        let client = cassette_client("teams/nested");
+        let teams = client.teams();
+        let parent = teams
+            .create("github", &NewTeam::new("Engineering").unwrap())
+            .await
+            .unwrap();
+        let child = teams
+            .create(
+                "github",
+                &NewTeam::new("Justice League")
+                    .unwrap()
+                    .privacy(Privacy::Closed)
+                    .parent_team_id(parent.id),
+            )
+            .await
+            .unwrap();
+        assert_eq!(child.parent.unwrap().slug, "engineering");
+        let children = teams.children("github", "engineering", None).await.unwrap();
+        assert_eq!(children[0].slug, "justice-league");
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::repos::NewPermission;
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::repos::Repository,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct SetPermission {
+    permission: NewPermission,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// The repositories that teams have access to. Teams also have access to the repositories of their parents.
+pub struct Repos<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Repos<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Repos { client }
+    }
+
+    /// Fetches a page of the repositories that a team has access to. `permissions` is the access of the team.
+    pub async fn list(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<Repository>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::GET,
+                        &format!("/orgs/{}/teams/{}/repos", org, team_slug),
+                    )
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every repository that a team has access to, page by page.
+    pub fn list_stream(
+        &self,
+        org: &'a str,
+        team_slug: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Repository, Error>> + 'a {
+        let repos = *self;
+        paginate_pages(params, limits, move |params| async move {
+            repos.list(org, team_slug, Some(params)).await
+        })
+    }
+
+    /// Checks if a team has access to a repository.
+    pub async fn has_access(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<bool, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/orgs/{}/teams/{}/repos/{}/{}", org, team_slug, owner, repo),
+            ))
+            .await?
+            .exists()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Gives a team access to a repository, or changes its permission. The repository has to belong to the organization.
+    pub async fn set_permission(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        permission: NewPermission,
+    ) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PUT,
+                        &format!("/orgs/{}/teams/{}/repos/{}/{}", org, team_slug, owner, repo),
+                    )
+                    .json(&SetPermission { permission })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Takes away the access of a team to a repository. Members keep any access that they have otherwise.
+    pub async fn remove(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/orgs/{}/teams/{}/repos/{}/{}", org, team_slug, owner, repo),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "auth")]
+mod tests {
+    use crate::{schema::repos::NewPermission, test_utils::cassette_client};
+
+    #[tokio::test]
+    async fn permissions_can_be_set() {
+        let client = cassette_client("teams/repos");
+        let repos = client.teams().repos();
+        repos
+            .set_permission(
+                "github",
+                "justice-league",
+                "github",
+                "Hello-World",
+                NewPermission::Maintain,
+            )
+            .await
+            .unwrap();
+        assert!(repos
+            .has_access("github", "justice-league", "github", "Hello-World")
+            .await
+            .unwrap());
+        repos
+            .remove("github", "justice-league", "github", "Hello-World")
+            .await
+            .unwrap();
+        assert!(!repos
+            .has_access("github", "justice-league", "github", "Hello-World")
+            .await
+            .unwrap());
+    }
+}
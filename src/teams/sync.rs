@@ -0,0 +1,107 @@
+use crate::{
+    schema::teams::{Group, GroupMapping},
+    transport::encode_segment,
+    Error, GithubClient,
+};
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Groups<'b> {
+    groups: &'b [Group],
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Team synchronization: keeping the members of teams in sync with the groups of an identity provider.
+/// Only available to organizations on the GitHub Enterprise Cloud plan with team synchronization turned on, through `api.github.com` like the rest of the API.
+/// Other organizations get `Error::Forbidden` or `Error::NotFound`.
+pub struct TeamSync<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> TeamSync<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        TeamSync { client }
+    }
+
+    /// Fetches the groups of the identity provider of an organization.
+    pub async fn groups(&self, org: &str) -> Result<GroupMapping, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/orgs/{}/team-sync/groups", org)),
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches the groups that a team is synced with.
+    pub async fn group_mappings(&self, org: &str, team_slug: &str) -> Result<GroupMapping, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!(
+                    "/orgs/{}/teams/{}/team-sync/group-mappings",
+                    org,
+                    encode_segment(team_slug)
+                ),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Replaces the groups that a team is synced with. An empty list stops syncing the team.
+    pub async fn set_group_mappings(
+        &self,
+        org: &str,
+        team_slug: &str,
+        groups: &[Group],
+    ) -> Result<GroupMapping, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(
+                        Method::PATCH,
+                        &format!(
+                            "/orgs/{}/teams/{}/team-sync/group-mappings",
+                            org,
+                            encode_segment(team_slug)
+                        ),
+                    )
+                    .json(&Groups { groups })?,
+            )
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{schema::teams::Group, test_utils::cassette_client};
+
+    #[tokio::test]
+    async fn group_mappings_can_be_replaced() {
+        let client = cassette_client("teams/sync");
+        let sync = client.teams().sync();
+        let groups = sync.groups("github").await.unwrap().groups;
+        assert!(sync
+            .group_mappings("github", "justice-league")
+            .await
+            .unwrap()
+            .groups
+            .is_empty());
+        let mapping = sync
+            .set_group_mappings("github", "justice-league", &groups[..1])
+            .await
+            .unwrap();
+        assert_eq!(
+            mapping.groups,
+            vec![Group::new(
+                "123",
+                "Octocat admins",
+                "The people who configure your octoworld."
+            )]
+        );
+    }
+}
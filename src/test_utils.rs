@@ -1,17 +1,144 @@
-#[macro_export]
-macro_rules! mock_response {
-    ($client:expr, $mod:literal, $fn:literal, $cfg:expr) => {{
-        use std::fs::read_to_string;
-        let cfg = format!("{:#?}", $cfg).replace(": ", "-").replace("\n", "").replace(" ", "").replace(",)", ")").replace(",}", "}").replace("\"", "");
+#[cfg(feature = "auth")]
+use crate::constants::FAKE_TOKEN;
+use crate::{
+    transport::{CassetteTransport, MockTransport, ReqwestTransport},
+    GithubClient,
+};
+use std::ops::Deref;
+
+fn test_client() -> GithubClient {
+    GithubClient::new(
+        #[cfg(feature = "enterprise")]
+        "https://something.com/api/v3",
         #[cfg(feature = "auth")]
-        let mut filename = format!("./tests/files/{mod}/{fn}/{cfg}.json", mod=$mod, fn=$fn, cfg=cfg);
-        #[cfg(not(feature = "auth"))]
-        let filename = format!("./tests/files/{mod}/{fn}/{cfg}.json", mod=$mod, fn=$fn, cfg=cfg);
-        dbg!(&filename);
-        #[cfg(feature = "auth")]
-        if $client.client.default_headers.get("Authorization").unwrap() == &format!("token {}", crate::constants::BAD_FAKE_TOKEN).parse::<::reqwest::header::HeaderValue>().unwrap() {
-            filename = "./tests/files/Bad_creds.json".to_owned();
+        FAKE_TOKEN,
+    )
+    .unwrap()
+}
+
+/// A client that answers from `mock` instead of going to GitHub.
+pub(crate) fn mock_client(mock: &MockTransport) -> GithubClient {
+    test_client().with_transport(mock.clone())
+}
+
+/// A client that replays a cassette, and checks that every interaction in it got replayed once it is dropped.
+/// That way, a test that skips a recorded request (like a step of a multi-step flow) fails instead of passing quietly.
+pub(crate) struct CassetteClient<C> {
+    client: C,
+    transport: CassetteTransport,
+}
+
+impl<C> Deref for CassetteClient<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.client
+    }
+}
+
+impl<C> Drop for CassetteClient<C> {
+    fn drop(&mut self) {
+        // Do not turn a failing test into a double panic.
+        if !std::thread::panicking() {
+            assert_eq!(
+                self.transport.unused(),
+                0,
+                "Not every recorded interaction was replayed"
+            );
         }
-        read_to_string(filename).unwrap()
-    }};
+    }
+}
+
+/// A client that replays `tests/cassettes/{name}.json`. Every interaction in the cassette has to be used by the end of the test.
+/// With `GITHUB_CASSETTE=record`, it goes to GitHub with the token in `GITHUB_TOKEN` instead, and records the cassette again.
+pub(crate) fn cassette_client(name: &str) -> CassetteClient<GithubClient> {
+    let (client, transport) = replay(name);
+    CassetteClient { client, transport }
+}
+
+/// The blocking version of `cassette_client`.
+#[cfg(feature = "blocking")]
+pub(crate) fn blocking_cassette_client(
+    name: &str,
+) -> CassetteClient<crate::blocking::GithubClient> {
+    let (client, transport) = replay(name);
+    CassetteClient {
+        client: crate::blocking::GithubClient::from_async(client).unwrap(),
+        transport,
+    }
+}
+
+fn replay(name: &str) -> (GithubClient, CassetteTransport) {
+    let path = format!("./tests/cassettes/{}.json", name);
+    let transport = CassetteTransport::from_env(path, ReqwestTransport::new()).unwrap();
+    let recording = matches!(
+        std::env::var(crate::transport::cassette::MODE_VAR).as_deref(),
+        Ok("record")
+    );
+    let client = if recording {
+        GithubClient::new(
+            #[cfg(feature = "enterprise")]
+            &std::env::var("GITHUB_BASE_URL").expect("GITHUB_BASE_URL is needed to record"),
+            #[cfg(feature = "auth")]
+            &std::env::var("GITHUB_TOKEN").expect("GITHUB_TOKEN is needed to record"),
+        )
+        .unwrap()
+    } else {
+        test_client()
+    };
+    (client.with_transport(transport.clone()), transport)
+}
+
+/// Starts a server on localhost that answers each connection with the next scripted raw HTTP response.
+/// `None` closes the connection without answering, like a connection reset would.
+/// Returns the base URL of the server and a counter of the connections it has gotten.
+pub(crate) async fn fake_server(
+    responses: Vec<Option<&'static str>>,
+) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&hits);
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let read = socket.read(&mut buf).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..read]);
+            }
+            if let Some(response) = response {
+                // Every connection only serves one response, so the client has to reconnect.
+                let response = response.replacen("\r\n", "\r\nconnection: close\r\n", 1);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        }
+    });
+    (base_url, hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Not every recorded interaction was replayed")]
+    async fn cassette_client_checks_every_interaction_is_replayed() {
+        // `users/follows` has two interactions, and this only uses the first one.
+        let client = cassette_client("users/follows");
+        assert!(client.users().follows("defunkt", "mojombo").await.unwrap());
+    }
 }
@@ -0,0 +1,391 @@
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use super::{Request, Response, Transport};
+use crate::Error;
+
+/// The environment variable that switches `CassetteTransport::from_env` to record mode, when set to `record`.
+pub const MODE_VAR: &str = "GITHUB_CASSETTE";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// A list of recorded request/response pairs, stored as JSON.
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A recorded request. The `Authorization` header is never recorded.
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub body: Body,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub body: Body,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+/// A body is kept as `json` when it is JSON, so that cassettes stay readable, as `text` when it is other UTF-8, and as `base64` otherwise (like archives).
+/// All of them are left out for an empty body.
+pub struct Body {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base64: Option<String>,
+}
+
+impl Body {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        if bytes.is_empty() {
+            return Self::default();
+        }
+        if let Ok(json) = serde_json::from_slice(bytes) {
+            return Self {
+                json: Some(json),
+                ..Self::default()
+            };
+        }
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Self {
+                text: Some(text.to_owned()),
+                ..Self::default()
+            },
+            Err(_) => Self {
+                base64: Some(STANDARD.encode(bytes)),
+                ..Self::default()
+            },
+        }
+    }
+
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        match (&self.json, &self.text, &self.base64) {
+            (Some(json), _, _) => Ok(serde_json::to_vec(json)?),
+            (None, Some(text), _) => Ok(text.as_bytes().to_vec()),
+            (None, None, Some(base64)) => STANDARD
+                .decode(base64)
+                .map_err(|err| Error::Transport(Box::new(err))),
+            (None, None, None) => Ok(Vec::new()),
+        }
+    }
+}
+
+impl Cassette {
+    /// Loads a cassette from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let text = fs::read_to_string(path).map_err(|err| Error::Transport(Box::new(err)))?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Saves the cassette as pretty printed JSON, creating the parent directories if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        fs::write(path, json)
+    }
+}
+
+impl RecordedRequest {
+    fn from_request(request: &Request) -> Self {
+        Self {
+            method: request.method.to_string(),
+            path: request.path.clone(),
+            query: request.query.clone(),
+            headers: headers_to_map(&request.headers, true),
+            body: Body::from_bytes(request.body.as_deref().unwrap_or_default()),
+        }
+    }
+
+    /// Strict matching: the method, path, query, and body all have to be the same. Headers are not compared.
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.path == other.path
+            && self.query == other.query
+            && self.body == other.body
+    }
+}
+
+impl RecordedResponse {
+    fn from_response(response: &Response) -> Self {
+        Self {
+            status: response.status.as_u16(),
+            headers: headers_to_map(&response.headers, false),
+            body: Body::from_bytes(&response.body),
+        }
+    }
+
+    fn to_response(&self) -> Result<Response, Error> {
+        let status =
+            StatusCode::from_u16(self.status).map_err(|err| Error::Transport(Box::new(err)))?;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) =
+                (name.parse::<HeaderName>(), value.parse::<HeaderValue>())
+            {
+                headers.insert(name, value);
+            }
+        }
+        Ok(Response {
+            status,
+            headers,
+            body: self.body.to_bytes()?,
+        })
+    }
+}
+
+fn headers_to_map(headers: &HeaderMap, redact: bool) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| !(redact && *name == AUTHORIZATION))
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+        .collect()
+}
+
+#[derive(Clone)]
+enum Mode {
+    Record(Arc<dyn Transport>),
+    Replay,
+}
+
+impl fmt::Debug for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Record(inner) => f.debug_tuple("Record").field(inner).finish(),
+            Mode::Replay => f.write_str("Replay"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    cassette: Cassette,
+    used: Vec<bool>,
+}
+
+#[derive(Debug, Clone)]
+/// A `Transport` that records interactions to a cassette file, or replays them from one.
+/// In record mode, requests go through the inner transport, and the cassette is saved after every response.
+/// In replay mode, every request has to match a recorded one (see `RecordedRequest`), and each recorded one is only used once. Nothing hits the network.
+/// Clones share the cassette, so a clone can be kept around to check `unused()` after handing the transport to a client.
+/// # Examples
+/// ```rust,no_run
+/// # #[cfg(feature = "auth")]
+/// # #[cfg(not(feature = "enterprise"))]
+/// # {
+/// use use_github_api::{transport::{CassetteTransport, ReqwestTransport}, GithubClient};
+/// // Records when `GITHUB_CASSETTE=record` is set, replays otherwise.
+/// let transport = CassetteTransport::from_env("tests/cassettes/octocat.json", ReqwestTransport::new()).unwrap();
+/// let client = GithubClient::new("token").unwrap().with_transport(transport);
+/// # }
+/// ```
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: Mode,
+    state: Arc<Mutex<State>>,
+}
+
+impl CassetteTransport {
+    /// Replays the cassette at `path`.
+    /// # Errors
+    /// Errors if the file cannot be read, or is not a cassette.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let cassette = Cassette::load(&path)?;
+        Ok(Self {
+            path: path.as_ref().to_owned(),
+            mode: Mode::Replay,
+            state: Arc::new(Mutex::new(State {
+                used: vec![false; cassette.interactions.len()],
+                cassette,
+            })),
+        })
+    }
+
+    /// Records into a new cassette at `path`, sending requests through `inner`. An existing cassette at `path` is overwritten.
+    pub fn record<T: Transport + 'static>(path: impl AsRef<Path>, inner: T) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+            mode: Mode::Record(Arc::new(inner)),
+            state: Arc::new(Mutex::new(State {
+                cassette: Cassette::default(),
+                used: Vec::new(),
+            })),
+        }
+    }
+
+    /// Records if the `GITHUB_CASSETTE` environment variable is `record`, and replays otherwise.
+    pub fn from_env<T: Transport + 'static>(
+        path: impl AsRef<Path>,
+        inner: T,
+    ) -> Result<Self, Error> {
+        match std::env::var(MODE_VAR) {
+            Ok(mode) if mode == "record" => Ok(Self::record(path, inner)),
+            _ => Self::replay(path),
+        }
+    }
+
+    /// How many recorded interactions have not been replayed yet. Always `0` in record mode.
+    pub fn unused(&self) -> usize {
+        let state = self.state.lock().unwrap();
+        state.used.iter().filter(|used| !**used).count()
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn send(&self, request: Request) -> Result<Response, Error> {
+        let recorded = RecordedRequest::from_request(&request);
+        match &self.mode {
+            Mode::Record(inner) => {
+                let response = inner.send(request).await?;
+                let mut state = self.state.lock().unwrap();
+                state.cassette.interactions.push(Interaction {
+                    request: recorded,
+                    response: RecordedResponse::from_response(&response),
+                });
+                state
+                    .cassette
+                    .save(&self.path)
+                    .map_err(|err| Error::Transport(Box::new(err)))?;
+                Ok(response)
+            }
+            Mode::Replay => {
+                let mut state = self.state.lock().unwrap();
+                let State { cassette, used } = &mut *state;
+                let index = cassette
+                    .interactions
+                    .iter()
+                    .zip(used.iter())
+                    .position(|(interaction, used)| !used && interaction.request.matches(&recorded))
+                    .ok_or_else(|| {
+                        Error::Transport(
+                            format!(
+                                "No unused interaction in {} for {} {}{}",
+                                self.path.display(),
+                                request.method,
+                                request.path,
+                                request
+                                    .query
+                                    .as_ref()
+                                    .map(|query| format!("?{}", query))
+                                    .unwrap_or_default()
+                            )
+                            .into(),
+                        )
+                    })?;
+                used[index] = true;
+                cassette.interactions[index].response.to_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use reqwest::Method;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "use-github-api-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    fn request(method: Method, path: &str) -> Request {
+        let mut request = Request::new(method, "https://api.github.com", path);
+        request
+            .headers
+            .insert(AUTHORIZATION, "token secret".parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn records_then_replays() {
+        let path = temp_path("records_then_replays");
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/users/octocat",
+            Response::new(StatusCode::OK, r#"{"login":"octocat"}"#).with_header("etag", "abc"),
+        );
+        let recorder = CassetteTransport::record(&path, mock);
+        recorder
+            .send(request(Method::GET, "/users/octocat"))
+            .await
+            .unwrap();
+
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(!saved.contains("secret"));
+        assert!(saved.contains(r#""login": "octocat""#));
+
+        let player = CassetteTransport::replay(&path).unwrap();
+        assert_eq!(player.unused(), 1);
+        let response = player
+            .send(request(Method::GET, "/users/octocat"))
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body, br#"{"login":"octocat"}"#);
+        assert_eq!(response.headers.get("etag").unwrap(), "abc");
+        assert_eq!(player.unused(), 0);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn replay_is_strict() {
+        let path = temp_path("replay_is_strict");
+        Cassette {
+            interactions: vec![Interaction {
+                request: RecordedRequest::from_request(&request(Method::GET, "/users")),
+                response: RecordedResponse::from_response(&Response::new(StatusCode::OK, "[]")),
+            }],
+        }
+        .save(&path)
+        .unwrap();
+        let player = CassetteTransport::replay(&path).unwrap();
+
+        let mut with_query = request(Method::GET, "/users");
+        with_query.query = Some("since=46".to_owned());
+        assert!(player.send(with_query).await.is_err());
+        assert!(player.send(request(Method::POST, "/users")).await.is_err());
+        player.send(request(Method::GET, "/users")).await.unwrap();
+        // Every interaction is only replayed once.
+        assert!(player.send(request(Method::GET, "/users")).await.is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use futures::{stream, StreamExt};
+use reqwest::Client;
+
+use super::{Request, Response, StreamingResponse, Transport};
+use crate::Error;
+
+#[derive(Debug, Clone, Default)]
+/// The default `Transport`, which sends requests with `reqwest`.
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a `ReqwestTransport` with a default `reqwest::Client`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a `ReqwestTransport` that uses an existing `reqwest::Client`, for example one with a proxy or timeouts set up.
+    pub fn from_client(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl ReqwestTransport {
+    async fn start(&self, request: Request) -> Result<reqwest::Response, Error> {
+        let mut builder = self
+            .client
+            .request(request.method.clone(), request.url())
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        Ok(builder.send().await?)
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: Request) -> Result<Response, Error> {
+        let response = self.start(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        Ok(Response {
+            status,
+            headers,
+            body: response.bytes().await?.to_vec(),
+        })
+    }
+
+    async fn send_streaming(&self, request: Request) -> Result<StreamingResponse, Error> {
+        let response = self.start(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = stream::try_unfold(response, |mut response| async move {
+            Ok(response.chunk().await?.map(|chunk| (chunk, response)))
+        });
+        Ok(StreamingResponse {
+            status,
+            headers,
+            body: body.boxed(),
+        })
+    }
+}
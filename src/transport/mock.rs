@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use reqwest::Method;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use super::{Request, Response, Transport};
+use crate::Error;
+
+#[derive(Debug)]
+struct Route {
+    method: Method,
+    path: String,
+    responses: VecDeque<Response>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// A `Transport` that answers with canned responses, for testing code that uses the client.
+/// Routes are matched on the method and the path. If the registered path has a query (like `/users?since=46`), the query has to match exactly as well.
+/// Registering the same route more than once makes it answer with each response in order, and then keep answering with the last one.
+/// Requests without a matching route fail with `Error::Transport`.
+/// # Examples
+/// ```rust
+/// # #[cfg(feature = "auth")]
+/// # #[cfg(not(feature = "enterprise"))]
+/// # {
+/// use reqwest::{Method, StatusCode};
+/// use use_github_api::{transport::{MockTransport, Response}, GithubClient};
+///
+/// let mock = MockTransport::new();
+/// mock.on(Method::GET, "/users/octocat", Response::new(StatusCode::OK, r#"{ "login": "octocat" }"#));
+/// let client = GithubClient::new("token").unwrap().with_transport(mock.clone());
+/// // Use `client`, then check what was sent with `mock.requests()`.
+/// # }
+/// ```
+pub struct MockTransport {
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockTransport {
+    /// Creates a `MockTransport` without any routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a response for a method and path.
+    pub fn on(&self, method: Method, path: &str, response: Response) -> &Self {
+        let mut routes = self.routes.lock().unwrap();
+        match routes
+            .iter_mut()
+            .find(|route| route.method == method && route.path == path)
+        {
+            Some(route) => route.responses.push_back(response),
+            None => routes.push(Route {
+                method,
+                path: path.to_owned(),
+                responses: VecDeque::from(vec![response]),
+            }),
+        }
+        self
+    }
+
+    /// Every request that has been sent so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: Request) -> Result<Response, Error> {
+        self.requests.lock().unwrap().push(request.clone());
+        let with_query = match &request.query {
+            Some(query) => format!("{}?{}", request.path, query),
+            None => request.path.clone(),
+        };
+        let mut routes = self.routes.lock().unwrap();
+        // An exact match with the query wins over a match on the path alone.
+        let index = routes
+            .iter()
+            .position(|route| route.method == request.method && route.path == with_query)
+            .or_else(|| {
+                routes
+                    .iter()
+                    .position(|route| route.method == request.method && route.path == request.path)
+            });
+        let route = index.map(|index| &mut routes[index]);
+        match route {
+            Some(route) if route.responses.len() > 1 => Ok(route.responses.pop_front().unwrap()),
+            Some(route) => Ok(route.responses[0].clone()),
+            None => Err(Error::Transport(
+                format!("No mock response for {} {}", request.method, with_query).into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    fn request(path: &str, query: Option<&str>) -> Request {
+        let mut request = Request::new(Method::GET, "https://api.github.com", path);
+        request.query = query.map(str::to_owned);
+        request
+    }
+
+    #[tokio::test]
+    async fn answers_in_order_then_repeats() {
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/users",
+            Response::new(StatusCode::BAD_GATEWAY, ""),
+        )
+        .on(Method::GET, "/users", Response::new(StatusCode::OK, "[]"));
+        let statuses = [
+            mock.send(request("/users", None)).await.unwrap().status,
+            mock.send(request("/users", None)).await.unwrap().status,
+            mock.send(request("/users", None)).await.unwrap().status,
+        ];
+        assert_eq!(
+            statuses,
+            [StatusCode::BAD_GATEWAY, StatusCode::OK, StatusCode::OK]
+        );
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn prefers_routes_with_the_query() {
+        let mock = MockTransport::new();
+        mock.on(
+            Method::GET,
+            "/users",
+            Response::new(StatusCode::OK, "first"),
+        )
+        .on(
+            Method::GET,
+            "/users?since=46",
+            Response::new(StatusCode::OK, "second"),
+        );
+        let first = mock
+            .send(request("/users", Some("per_page=5")))
+            .await
+            .unwrap();
+        assert_eq!(first.body, b"first");
+        let second = mock
+            .send(request("/users", Some("since=46")))
+            .await
+            .unwrap();
+        assert_eq!(second.body, b"second");
+    }
+
+    #[tokio::test]
+    async fn errors_without_a_route() {
+        let mock = MockTransport::new();
+        mock.on(Method::POST, "/users", Response::new(StatusCode::OK, ""));
+        let err = mock.send(request("/users", None)).await.unwrap_err();
+        assert!(matches!(err, Error::Transport(_)));
+    }
+}
@@ -0,0 +1,255 @@
+//! The layer that actually sends requests.
+//! `GithubClient` builds a `Request` for every call and hands it to a `Transport`, which gives back a `Response`.
+//! By default, that is a `ReqwestTransport`. In tests, a `MockTransport` or a `CassetteTransport` can be used instead, so nothing hits the network.
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, StatusCode,
+};
+use serde::Serialize;
+use std::fmt;
+
+use crate::Error;
+
+pub mod cassette;
+mod http;
+mod mock;
+
+pub use cassette::CassetteTransport;
+pub use http::ReqwestTransport;
+pub use mock::MockTransport;
+
+#[async_trait]
+/// Sends a `Request` and reads the whole `Response`.
+/// Errors should only be returned when there is no response at all (like when the connection fails), since error statuses are handled by the client.
+/// # Examples
+/// ```rust
+/// use async_trait::async_trait;
+/// use use_github_api::{transport::{Request, Response, Transport}, Error};
+///
+/// #[derive(Debug)]
+/// struct AlwaysEmpty;
+///
+/// #[async_trait]
+/// impl Transport for AlwaysEmpty {
+///     async fn send(&self, _request: Request) -> Result<Response, Error> {
+///         Ok(Response::new(reqwest::StatusCode::OK, "[]"))
+///     }
+/// }
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+    async fn send(&self, request: Request) -> Result<Response, Error>;
+
+    /// Sends a `Request`, and gives back the body as it comes in instead of reading all of it first. Used for big downloads, like archives.
+    /// By default, the whole response is read with `send`, and the body comes as a single chunk.
+    async fn send_streaming(&self, request: Request) -> Result<StreamingResponse, Error> {
+        let response = self.send(request).await?;
+        let body = Bytes::from(response.body);
+        Ok(StreamingResponse {
+            status: response.status,
+            headers: response.headers,
+            body: futures::stream::once(async move { Ok(body) }).boxed(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+/// A request to the API. Apart from the method and path, the client fills in the base URL and the default headers (auth, user agent, etc.).
+pub struct Request {
+    pub method: Method,
+    pub base_url: String,
+    /// The path after the base URL, like `/users/octocat`.
+    pub path: String,
+    /// The URL encoded query, without the `?`.
+    pub query: Option<String>,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+impl Request {
+    /// Creates a request without any headers, query, or body.
+    pub fn new(method: Method, base_url: &str, path: &str) -> Self {
+        Self {
+            method,
+            base_url: base_url.to_owned(),
+            path: path.to_owned(),
+            query: None,
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    /// The full URL, including the query.
+    pub fn url(&self) -> String {
+        match &self.query {
+            Some(query) => format!("{}{}?{}", self.base_url, self.path, query),
+            None => format!("{}{}", self.base_url, self.path),
+        }
+    }
+
+    /// Encodes `query` into the query string. Fields that are `None` are left out.
+    pub(crate) fn query<T: Serialize + ?Sized>(mut self, query: &T) -> Result<Self, Error> {
+        let encoded =
+            serde_urlencoded::to_string(query).map_err(|err| Error::Transport(Box::new(err)))?;
+        if !encoded.is_empty() {
+            self.query = Some(match self.query {
+                Some(existing) => format!("{}&{}", existing, encoded),
+                None => encoded,
+            });
+        }
+        Ok(self)
+    }
+
+    /// Encodes `body` as JSON and sets it as the body.
+    #[cfg_attr(not(feature = "auth"), allow(dead_code))]
+    pub(crate) fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Self, Error> {
+        self.body = Some(serde_json::to_vec(body)?);
+        self.headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        Ok(self)
+    }
+}
+
+/// Percent-encodes every segment of a path (like a file path in a repository), keeping the `/` between them.
+pub(crate) fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(encode_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Percent-encodes a value that goes into a single path segment, like a label name with spaces or slashes.
+pub(crate) fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+/// A response that has been fully read, so that it can be inspected after the connection is gone.
+pub struct Response {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates a response without any headers.
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Adds a header.
+    /// # Panics
+    /// Panics if the name or the value is not a valid header name or value.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(
+            name.parse::<HeaderName>().expect("Invalid header name"),
+            value.parse().expect("Invalid header value"),
+        );
+        self
+    }
+}
+
+#[non_exhaustive]
+/// A response whose body has not been read yet. It comes in chunks, as it is downloaded.
+pub struct StreamingResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: BoxStream<'static, Result<Bytes, Error>>,
+}
+
+impl StreamingResponse {
+    /// Creates a streaming response without any headers.
+    pub fn new(status: StatusCode, body: BoxStream<'static, Result<Bytes, Error>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body,
+        }
+    }
+
+    /// Reads the whole body.
+    pub async fn read(self) -> Result<Response, Error> {
+        let body = self
+            .body
+            .try_fold(Vec::new(), |mut body, chunk| async move {
+                body.extend_from_slice(&chunk);
+                Ok(body)
+            })
+            .await?;
+        Ok(Response {
+            status: self.status,
+            headers: self.headers,
+            body,
+        })
+    }
+}
+
+impl fmt::Debug for StreamingResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_urls() {
+        let request = Request::new(Method::GET, "https://api.github.com", "/users");
+        assert_eq!(request.url(), "https://api.github.com/users");
+        let request = request
+            .query(&Some(
+                crate::schema::users::list::Params::new(Some(46), None).unwrap(),
+            ))
+            .unwrap();
+        assert_eq!(request.url(), "https://api.github.com/users?since=46");
+    }
+
+    #[test]
+    fn encodes_path_segments() {
+        assert_eq!(encode_segment("bug"), "bug");
+        assert_eq!(
+            encode_segment("good first issue/🚀?"),
+            "good%20first%20issue%2F%F0%9F%9A%80%3F"
+        );
+    }
+
+    #[test]
+    fn encodes_paths_by_segment() {
+        assert_eq!(encode_path("config/app.yml"), "config/app.yml");
+        assert_eq!(
+            encode_path("docs/C# notes/100%?.md"),
+            "docs/C%23%20notes/100%25%3F.md"
+        );
+    }
+
+    #[test]
+    fn skips_empty_queries() {
+        let request = Request::new(Method::GET, "https://api.github.com", "/users")
+            .query(&None::<crate::schema::users::list::Params>)
+            .unwrap();
+        assert_eq!(request.query, None);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::users::list,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Blocking and unblocking users for the current authenticated user. Requires auth.
+pub struct Blocks<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Blocks<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Blocks { client }
+    }
+
+    /// Fetches a page of the users that the current authenticated user has blocked.
+    pub async fn list(&self, params: Option<PageParams>) -> Result<Vec<list::User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/blocks")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every blocked user, page by page.
+    pub fn list_stream(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::User, Error>> + 'a {
+        let blocks = *self;
+        paginate_pages(params, limits, move |params| async move {
+            blocks.list(Some(params)).await
+        })
+    }
+
+    /// Checks if the current authenticated user has blocked a user.
+    pub async fn is_blocked(&self, username: &str) -> Result<bool, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/user/blocks/{}", username)),
+            )
+            .await?
+            .exists()
+    }
+
+    /// Blocks a user.
+    /// # Errors
+    /// Will error if the user does not exist, or if it is the current authenticated user.
+    pub async fn block(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PUT, &format!("/user/blocks/{}", username)),
+            )
+            .await?
+            .json()
+    }
+
+    /// Unblocks a user.
+    pub async fn unblock(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::DELETE, &format!("/user/blocks/{}", username)),
+            )
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn list_stream_works() {
+        use futures::TryStreamExt;
+        let client = cassette_client("users/blocks_list");
+        let data: Vec<list::User> = client
+            .users()
+            .blocks()
+            .list_stream(None, Limits::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].login, "spammer");
+    }
+
+    #[tokio::test]
+    async fn is_blocked_maps_the_status() {
+        let client = cassette_client("users/blocks_check");
+        let blocks = client.users().blocks();
+        assert!(blocks.is_blocked("spammer").await.unwrap());
+        assert!(!blocks.is_blocked("octocat").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn block_and_unblock_work() {
+        let client = cassette_client("users/blocks");
+        let blocks = client.users().blocks();
+        blocks.block("spammer").await.unwrap();
+        blocks.unblock("spammer").await.unwrap();
+    }
+}
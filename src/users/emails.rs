@@ -0,0 +1,148 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::users::emails::{Email, Visibility},
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct EmailsBody<'b> {
+    emails: &'b [&'b str],
+}
+
+#[derive(Serialize)]
+struct VisibilityBody {
+    visibility: Visibility,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// The email addresses of the current authenticated user. Requires auth.
+pub struct Emails<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> Emails<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        Emails { client }
+    }
+
+    /// Fetches a page of the current authenticated user's emails, including the private ones.
+    pub async fn list(&self, params: Option<PageParams>) -> Result<Vec<Email>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/emails")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every email, page by page.
+    pub fn list_stream(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Email, Error>> + 'a {
+        let emails = *self;
+        paginate_pages(params, limits, move |params| async move {
+            emails.list(Some(params)).await
+        })
+    }
+
+    /// Fetches a page of the current authenticated user's public emails.
+    pub async fn list_public(&self, params: Option<PageParams>) -> Result<Vec<Email>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/public_emails")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Adds emails to the current authenticated user, and returns the added ones. They start out unverified.
+    /// # Errors
+    /// Will error if one of the emails is invalid or already in use.
+    pub async fn add(&self, emails: &[&str]) -> Result<Vec<Email>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, "/user/emails")
+                    .json(&EmailsBody { emails })?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Deletes emails from the current authenticated user.
+    /// # Errors
+    /// Will error if one of the emails is the primary one.
+    pub async fn delete(&self, emails: &[&str]) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::DELETE, "/user/emails")
+                    .json(&EmailsBody { emails })?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Sets whether the primary email is shown on the current authenticated user's profile, and returns the updated emails.
+    pub async fn set_primary_visibility(
+        &self,
+        visibility: Visibility,
+    ) -> Result<Vec<Email>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PATCH, "/user/email/visibility")
+                    .json(&VisibilityBody { visibility })?,
+            )
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn list_works() {
+        let client = cassette_client("users/emails_list");
+        let data = client.users().emails().list(None).await.unwrap();
+        assert_eq!(data.len(), 2);
+        assert!(data[0].primary && data[0].verified);
+        assert_eq!(data[0].visibility, Some(Visibility::Public));
+        assert_eq!(data[1].visibility, None);
+    }
+
+    #[tokio::test]
+    async fn add_and_delete_work() {
+        let client = cassette_client("users/emails");
+        let emails = client.users().emails();
+        let added = emails.add(&["octocat@example.com"]).await.unwrap();
+        assert_eq!(added[0].email, "octocat@example.com");
+        assert!(!added[0].verified);
+        emails.delete(&["octocat@example.com"]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn set_primary_visibility_works() {
+        let client = cassette_client("users/emails_visibility");
+        let data = client
+            .users()
+            .emails()
+            .set_primary_visibility(Visibility::Private)
+            .await
+            .unwrap();
+        assert_eq!(data[0].visibility, Some(Visibility::Private));
+    }
+}
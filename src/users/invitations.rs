@@ -0,0 +1,83 @@
+use crate::{
+    pagination::{paginate_pages, Limits, PageParams},
+    schema::repos::invitations::Invitation,
+    Error, GithubClient,
+};
+use futures::Stream;
+use reqwest::Method;
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// The invitations that the current authenticated user has gotten to collaborate on repositories. Requires auth.
+pub struct RepositoryInvitations<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> RepositoryInvitations<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        RepositoryInvitations { client }
+    }
+
+    /// Fetches a page of the open invitations of the current authenticated user.
+    pub async fn list(&self, params: Option<PageParams>) -> Result<Vec<Invitation>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/repository_invitations")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every open invitation, page by page.
+    pub fn list_stream(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<Invitation, Error>> + 'a {
+        let invitations = *self;
+        paginate_pages(params, limits, move |params| async move {
+            invitations.list(Some(params)).await
+        })
+    }
+
+    /// Accepts an invitation, which makes the current authenticated user a collaborator of the repository.
+    /// # Errors
+    /// Will error with `Error::Conflict` if the invitation has expired.
+    pub async fn accept(&self, invitation_id: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::PATCH,
+                &format!("/user/repository_invitations/{}", invitation_id),
+            ))
+            .await?
+            .json()
+    }
+
+    /// Declines an invitation.
+    pub async fn decline(&self, invitation_id: usize) -> Result<(), Error> {
+        self.client
+            .send(self.client.request(
+                Method::DELETE,
+                &format!("/user/repository_invitations/{}", invitation_id),
+            ))
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn invitations_can_be_answered() {
+        let client = cassette_client("users/repository_invitations");
+        let invitations = client.users().repository_invitations();
+        let open = invitations.list(None).await.unwrap();
+        assert_eq!(open[0].repository.full_name, "octocat/Hello-World");
+        invitations.accept(open[0].id).await.unwrap();
+        invitations.decline(open[1].id).await.unwrap();
+    }
+}
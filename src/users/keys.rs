@@ -0,0 +1,277 @@
+#[cfg(feature = "auth")]
+use crate::pagination::paginate_pages;
+#[cfg(any(feature = "auth", doc))]
+use crate::pagination::Limits;
+use crate::{
+    pagination::PageParams,
+    schema::users::keys::{GpgKey, SshKey},
+    Error, GithubClient,
+};
+#[cfg(any(feature = "auth", doc))]
+use futures::Stream;
+use reqwest::Method;
+#[cfg(feature = "auth")]
+use serde::Serialize;
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct NewSshKey<'b> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'b str>,
+    key: &'b str,
+}
+
+#[cfg(feature = "auth")]
+#[derive(Serialize)]
+struct NewGpgKey<'b> {
+    armored_public_key: &'b str,
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// Public SSH keys. Managing the keys of the current authenticated user requires auth, but anyone's public keys can be listed.
+pub struct SshKeys<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> SshKeys<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        SshKeys { client }
+    }
+
+    /// Fetches a page of the public SSH keys of any user.
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<SshKey>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/keys", username))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the current authenticated user's SSH keys.
+    pub async fn list(&self, params: Option<PageParams>) -> Result<Vec<SshKey>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/keys")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every SSH key of the current authenticated user, page by page.
+    pub fn list_stream(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<SshKey, Error>> + 'a {
+        let keys = *self;
+        paginate_pages(params, limits, move |params| async move {
+            keys.list(Some(params)).await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches one of the current authenticated user's SSH keys.
+    /// # Errors
+    /// Will error if the key does not exist.
+    pub async fn get(&self, id: usize) -> Result<SshKey, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/user/keys/{}", id)),
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds an SSH key to the current authenticated user. Needs the `write:public_key` scope.
+    /// # Errors
+    /// Will error if the key is invalid or already in use.
+    pub async fn create(&self, title: Option<&str>, key: &str) -> Result<SshKey, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, "/user/keys")
+                    .json(&NewSshKey { title, key })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes one of the current authenticated user's SSH keys. Needs the `admin:public_key` scope.
+    pub async fn delete(&self, id: usize) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::DELETE, &format!("/user/keys/{}", id)),
+            )
+            .await?
+            .json()
+    }
+}
+
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+/// GPG keys. Managing the keys of the current authenticated user requires auth, but anyone's keys can be listed.
+pub struct GpgKeys<'a> {
+    client: &'a GithubClient,
+}
+
+impl<'a> GpgKeys<'a> {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
+        GpgKeys { client }
+    }
+
+    /// Fetches a page of the GPG keys of any user.
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<GpgKey>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/gpg_keys", username))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches a page of the current authenticated user's GPG keys.
+    pub async fn list(&self, params: Option<PageParams>) -> Result<Vec<GpgKey>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, "/user/gpg_keys")
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches every GPG key of the current authenticated user, page by page.
+    pub fn list_stream(
+        &self,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<GpgKey, Error>> + 'a {
+        let keys = *self;
+        paginate_pages(params, limits, move |params| async move {
+            keys.list(Some(params)).await
+        })
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Fetches one of the current authenticated user's GPG keys.
+    /// # Errors
+    /// Will error if the key does not exist.
+    pub async fn get(&self, id: usize) -> Result<GpgKey, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/user/gpg_keys/{}", id)),
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Adds an ASCII armored GPG key to the current authenticated user. Needs the `write:gpg_key` scope.
+    /// # Errors
+    /// Will error if the key is invalid or already in use.
+    pub async fn create(&self, armored_public_key: &str) -> Result<GpgKey, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::POST, "/user/gpg_keys")
+                    .json(&NewGpgKey { armored_public_key })?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Deletes one of the current authenticated user's GPG keys. Needs the `admin:gpg_key` scope.
+    pub async fn delete(&self, id: usize) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::DELETE, &format!("/user/gpg_keys/{}", id)),
+            )
+            .await?
+            .json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::cassette_client;
+
+    #[tokio::test]
+    async fn list_for_user_works() {
+        let client = cassette_client("users/keys_list");
+        let data = client
+            .users()
+            .ssh_keys()
+            .list_for_user("octocat", None)
+            .await
+            .unwrap();
+        assert_eq!(data[0].id, 3);
+        assert_eq!(data[0].title, None);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn ssh_create_and_delete_work() {
+        let client = cassette_client("users/keys");
+        let keys = client.users().ssh_keys();
+        let key = keys
+            .create(Some("laptop"), "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIA")
+            .await
+            .unwrap();
+        assert_eq!(key.title.as_deref(), Some("laptop"));
+        assert_eq!(key.verified, Some(true));
+        keys.delete(key.id).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn gpg_keys_have_subkeys_and_expiry() {
+        let client = cassette_client("users/gpg_keys");
+        let key = client.users().gpg_keys().get(3).await.unwrap();
+        assert_eq!(key.emails[0].email, "octocat@users.noreply.github.com");
+        assert_eq!(key.subkeys[0].primary_key_id, Some(3));
+        assert!(key.subkeys[0].subkeys.is_empty());
+        assert_eq!(
+            key.expires_at.unwrap().to_rfc3339(),
+            "2025-03-10T22:31:57+00:00"
+        );
+        assert_eq!(key.subkeys[0].expires_at, None);
+    }
+}
@@ -1,56 +1,84 @@
-#![allow(unused_imports)]
-use crate::url;
+#[cfg(any(feature = "auth", doc))]
+use crate::schema::users::{contextual_info, current};
 use crate::{
-    check_for_errors,
-    schema::{
-        users::{contextual_info, current, list, single},
-        GitHubError,
-    },
-    GithubClient,
+    pagination::{paginate, paginate_pages, Limits, PageParams},
+    schema::users::{list, single},
+    Error, GithubClient,
 };
-use serde_json::from_str;
-use std::error::Error;
+use futures::Stream;
+use reqwest::Method;
 
-pub mod error;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod blocks;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod emails;
+#[cfg(any(feature = "auth", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+pub mod invitations;
+pub mod keys;
+#[cfg(any(feature = "auth", doc))]
+use blocks::Blocks;
+#[cfg(any(feature = "auth", doc))]
+use emails::Emails;
+#[cfg(any(feature = "auth", doc))]
+use invitations::RepositoryInvitations;
+use keys::{GpgKeys, SshKeys};
 
+#[derive(Clone, Copy)]
 #[non_exhaustive]
 pub struct Users<'a> {
-    #[cfg_attr(test, allow(dead_code))]
-    client: &'a GithubClient<'a>,
+    client: &'a GithubClient,
 }
 
 impl<'a> Users<'a> {
-    pub(crate) fn new(client: &'a GithubClient<'a>) -> Self {
+    pub(crate) fn new(client: &'a GithubClient) -> Self {
         Users { client }
     }
 
     /// Fetches a list of users.
-    pub async fn list(&self, cfg: Option<list::Params>) -> Result<Vec<list::User>, Box<dyn Error>> {
-        #[cfg(test)]
-        let text = crate::mock_response!(&self, "users", "list", cfg);
-        #[cfg(not(test))]
-        let text = {
-            let result = self
-                .client
-                .reqwest_client
-                .get(url!(self, "/users"))
-                .query(&cfg)
-                .send()
-                .await?;
-            result.text().await?
-        };
-        let json_result = from_str::<Vec<list::User>>(&text);
-        match json_result {
-            Ok(data) => Ok(data),
-            Err(err) => {
-                if err.is_data() {
-                    let error_data = from_str::<GitHubError>(&text)?;
-                    check_for_errors!(error_data, err);
-                } else {
-                    Err(err.into())
-                }
-            }
-        }
+    pub async fn list(&self, cfg: Option<list::Params>) -> Result<Vec<list::User>, Error> {
+        self.client
+            .send(self.client.request(Method::GET, "/users").query(&cfg)?)
+            .await?
+            .json()
+    }
+
+    /// Fetches every user, page by page, following the `since` cursor of the `Link` header.
+    /// `cfg` is used for the first page; `per_page` is kept for the pages after it.
+    /// Use `limits` to cap how many pages or users are fetched, since the list of users on GitHub is huge.
+    /// # Examples
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    /// use use_github_api::pagination::Limits;
+    /// let users: Vec<_> = client
+    ///     .users()
+    ///     .list_stream(None, Limits::new().max_items(500))
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn list_stream(
+        &self,
+        cfg: Option<list::Params>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::User, Error>> + 'a {
+        let per_page = cfg.as_ref().and_then(|cfg| cfg.per_page);
+        let users = *self;
+        paginate(
+            cfg,
+            limits,
+            move |cfg| async move { users.list(cfg).await },
+            move |link| {
+                let since = crate::pagination::query_param(link, "since")?
+                    .parse()
+                    .ok()?;
+                Some(Some(list::Params {
+                    since: Some(since),
+                    per_page,
+                }))
+            },
+        )
     }
 
     /// Fetches a specific user.
@@ -58,31 +86,83 @@ impl<'a> Users<'a> {
     /// If the current authenticated user is the same as the user being fetched, a few more fields will exist.
     /// # Errors
     /// Will error if the user does not exist.
-    pub async fn user(&self, username: &str) -> Result<single::User, Box<dyn Error>> {
-        #[cfg(test)]
-        let text = crate::mock_response!(&self, "users", "user", username);
-        #[cfg(not(test))]
-        let text = {
-            let result = self
-                .client
-                .reqwest_client
-                .get(url!(self, "/users/{}", username))
-                .send()
-                .await?;
-            result.text().await?
-        };
-
-        match from_str::<single::User>(&text) {
-            Ok(data) => Ok(data),
-            Err(err) => {
-                if err.is_data() {
-                    let error_data = from_str::<GitHubError>(&text)?;
-                    check_for_errors!(error_data, err);
-                } else {
-                    Err(err.into())
-                }
-            }
-        }
+    pub async fn user(&self, username: &str) -> Result<single::User, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}", username)),
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches a page of the users that follow a user.
+    pub async fn followers(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/followers", username))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every user that follows a user, page by page.
+    pub fn followers_stream(
+        &self,
+        username: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::User, Error>> + 'a {
+        let users = *self;
+        paginate_pages(params, limits, move |params| async move {
+            users.followers(username, Some(params)).await
+        })
+    }
+
+    /// Fetches a page of the users that a user follows.
+    pub async fn following(
+        &self,
+        username: &str,
+        params: Option<PageParams>,
+    ) -> Result<Vec<list::User>, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/following", username))
+                    .query(&params)?,
+            )
+            .await?
+            .json()
+    }
+
+    /// Fetches every user that a user follows, page by page.
+    pub fn following_stream(
+        &self,
+        username: &'a str,
+        params: Option<PageParams>,
+        limits: Limits,
+    ) -> impl Stream<Item = Result<list::User, Error>> + 'a {
+        let users = *self;
+        paginate_pages(params, limits, move |params| async move {
+            users.following(username, Some(params)).await
+        })
+    }
+
+    /// Checks if `username` follows `target`.
+    pub async fn follows(&self, username: &str, target: &str) -> Result<bool, Error> {
+        self.client
+            .send(self.client.request(
+                Method::GET,
+                &format!("/users/{}/following/{}", username, target),
+            ))
+            .await?
+            .exists()
     }
 
     #[cfg(any(feature = "auth", doc))]
@@ -95,115 +175,229 @@ impl<'a> Users<'a> {
         &self,
         username: &str,
         cfg: Option<contextual_info::Params>,
-    ) -> Result<contextual_info::User, Box<dyn Error>> {
-        #[cfg(test)]
-        let text = crate::mock_response!(&self, "users", "contextual_info", (username, cfg));
-        #[cfg(not(test))]
-        let text = {
-            let result = self
-                .client
-                .reqwest_client
-                .get(url!(self, "/users/{}/hovercard", username))
-                .query(&cfg)
-                .send()
-                .await?;
-            result.text().await?
-        };
-
-        match from_str::<contextual_info::User>(&text) {
-            Ok(data) => Ok(data),
-            Err(err) => {
-                if err.is_data() {
-                    let error_data = from_str::<GitHubError>(&text)?;
-                    check_for_errors!(error_data, err);
-                } else {
-                    Err(err.into())
-                }
-            }
-        }
+    ) -> Result<contextual_info::User, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/users/{}/hovercard", username))
+                    .query(&cfg)?,
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The users blocked by the current authenticated user.
+    pub fn blocks(&self) -> Blocks<'a> {
+        Blocks::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The email addresses of the current authenticated user.
+    pub fn emails(&self) -> Emails<'a> {
+        Emails::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// The invitations that the current authenticated user has gotten to collaborate on repositories.
+    pub fn repository_invitations(&self) -> RepositoryInvitations<'a> {
+        RepositoryInvitations::new(self.client)
+    }
+
+    /// Public SSH keys, of the current authenticated user or of anyone else.
+    pub fn ssh_keys(&self) -> SshKeys<'a> {
+        SshKeys::new(self.client)
+    }
+
+    /// GPG keys, of the current authenticated user or of anyone else.
+    pub fn gpg_keys(&self) -> GpgKeys<'a> {
+        GpgKeys::new(self.client)
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Checks if the current authenticated user follows `target`.
+    pub async fn is_following(&self, target: &str) -> Result<bool, Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::GET, &format!("/user/following/{}", target)),
+            )
+            .await?
+            .exists()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Follows a user as the current authenticated user. Needs the `user:follow` scope.
+    pub async fn follow(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::PUT, &format!("/user/following/{}", username)),
+            )
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Unfollows a user as the current authenticated user. Needs the `user:follow` scope.
+    pub async fn unfollow(&self, username: &str) -> Result<(), Error> {
+        self.client
+            .send(
+                self.client
+                    .request(Method::DELETE, &format!("/user/following/{}", username)),
+            )
+            .await?
+            .json()
     }
 
     #[cfg(any(feature = "auth", doc))]
     #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
     /// Fetches the current authenticated user.
-    pub async fn current(&self) -> Result<current::User, Box<dyn Error>> {
-        #[cfg(test)]
-        let text = crate::mock_response!(&self, "users", "current", &self.client.auth_token);
-        #[cfg(not(test))]
-        let text = {
-            let result = self
-                .client
-                .reqwest_client
-                .get(url!(self, "/user"))
-                .send()
-                .await?;
-            result.text().await?
-        };
-
-        match from_str::<current::User>(&text) {
-            Ok(data) => Ok(data),
-            Err(err) => {
-                if err.is_data() {
-                    let error_data = from_str::<GitHubError>(&text)?;
-                    check_for_errors!(error_data, err);
-                } else {
-                    Err(err.into())
-                }
-            }
-        }
+    pub async fn current(&self) -> Result<current::User, Error> {
+        self.client
+            .send(self.client.request(Method::GET, "/user"))
+            .await?
+            .json()
+    }
+
+    #[cfg(any(feature = "auth", doc))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
+    /// Updates the profile of the current authenticated user, and returns the updated user.
+    /// # Errors
+    /// Will error if the token does not have the `user` scope, or if `email` is not one of the user's verified emails.
+    /// # Examples
+    /// ```rust,ignore
+    /// use use_github_api::schema::users::current::UpdateParams;
+    /// let user = client
+    ///     .users()
+    ///     .update_current(UpdateParams::new().bio("I am a bot").hireable(false))
+    ///     .await?;
+    /// ```
+    pub async fn update_current(
+        &self,
+        params: current::UpdateParams,
+    ) -> Result<current::User, Error> {
+        self.client
+            .send(self.client.request(Method::PATCH, "/user").json(&params)?)
+            .await?
+            .json()
     }
 }
 
-// NOTE: All of these tests are really just sanity checks. They just read fake responses from the filesystem.
-// These do not hit the API, since rate limits are strict and auth tokens are also required.
-// Technically, these are tests for the schema and not for the functions, but this is the best that can be done.
+// NOTE: All of these tests replay cassettes from `tests/cassettes`, so they do not hit the API.
+// Rate limits are strict and auth tokens are also required, so the cassettes are only recorded again when an endpoint changes.
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "auth")]
-    use crate::constants::FAKE_TOKEN;
-
     use super::*;
+    use crate::test_utils::cassette_client;
 
     #[tokio::test]
     async fn list_works() {
-        let client = GithubClient::new(
-            #[cfg(feature = "enterprise")]
-            "https://something.com/api/v3",
-            #[cfg(feature = "auth")]
-            FAKE_TOKEN,
-        )
-        .unwrap();
-
+        let client = cassette_client("users/list_first_page");
         let users = Users::new(&client);
         let data = users.list(None).await.unwrap();
         assert_eq!(data[0].login, "mojombo");
         assert_eq!(data[0].id, 1);
     }
 
+    #[tokio::test]
+    async fn list_stream_follows_since() {
+        use futures::TryStreamExt;
+        let client = cassette_client("users/list");
+        let users = Users::new(&client);
+        let data: Vec<list::User> = users
+            .list_stream(None, Limits::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 32);
+        assert_eq!(data[0].login, "mojombo");
+        assert_eq!(data[31].id, 48);
+    }
+
+    #[tokio::test]
+    async fn list_stream_respects_limits() {
+        use futures::TryStreamExt;
+        let client = cassette_client("users/list_first_page");
+        let users = Users::new(&client);
+        let data: Vec<list::User> = users
+            .list_stream(None, Limits::new().max_items(5))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 5);
+
+        let client = cassette_client("users/list_first_page");
+        let users = Users::new(&client);
+        let data: Vec<list::User> = users
+            .list_stream(None, Limits::new().max_pages(1))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 30);
+    }
+
     #[tokio::test]
     async fn single_works() {
-        let client = GithubClient::new(
-            #[cfg(feature = "enterprise")]
-            "https://something.com/api/v3",
-            #[cfg(feature = "auth")]
-            FAKE_TOKEN,
-        )
-        .unwrap();
+        let client = cassette_client("users/user");
         let users = Users::new(&client);
         let data = users.user("mojombo").await.unwrap();
         assert_eq!(data.login, "mojombo");
     }
 
+    #[tokio::test]
+    async fn works_from_spawned_tasks() {
+        let client = cassette_client("users/user");
+        let task_client = client.clone();
+        let data = tokio::spawn(async move { task_client.users().user("mojombo").await })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(data.login, "mojombo");
+    }
+
+    #[tokio::test]
+    async fn followers_stream_works() {
+        use futures::TryStreamExt;
+        let client = cassette_client("users/followers");
+        let data: Vec<list::User> = client
+            .users()
+            .followers_stream("mojombo", None, Limits::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[1].login, "pjhyett");
+    }
+
+    #[tokio::test]
+    async fn follows_maps_the_status() {
+        let client = cassette_client("users/follows");
+        let users = client.users();
+        assert!(users.follows("defunkt", "mojombo").await.unwrap());
+        assert!(!users.follows("mojombo", "defunkt").await.unwrap());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn follow_and_unfollow_work() {
+        let client = cassette_client("users/following");
+        let users = client.users();
+        users.follow("mojombo").await.unwrap();
+        assert!(users.is_following("mojombo").await.unwrap());
+        users.unfollow("mojombo").await.unwrap();
+    }
+
     #[tokio::test]
     #[cfg(feature = "auth")]
     async fn context_info_works() {
-        #[cfg(feature = "auth")]
-        let client = GithubClient::new(
-            #[cfg(feature = "enterprise")]
-            "https://something.com/api/v3",
-            FAKE_TOKEN,
-        )
-        .unwrap();
+        let client = cassette_client("users/contextual_info");
         let users = Users::new(&client);
         let data = users.contextual_info("mojombo", None).await.unwrap();
         assert_eq!(data.contexts[0].message, "Member of @toml-lang");
@@ -212,14 +406,35 @@ mod tests {
     #[tokio::test]
     #[cfg(feature = "auth")]
     async fn current_user_works() {
-        let client = GithubClient::new(
-            #[cfg(feature = "enterprise")]
-            "https://something.com/api/v3",
-            FAKE_TOKEN,
-        )
-        .unwrap();
+        let client = cassette_client("users/current");
         let users = Users::new(&client);
         let data = users.current().await.unwrap();
         assert_eq!(data.name, Some("Advaiya Lad".to_owned()));
     }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn update_current_works() {
+        let client = cassette_client("users/update_current");
+        let users = Users::new(&client);
+        let data = users
+            .update_current(
+                current::UpdateParams::new()
+                    .bio("Rust and Python")
+                    .hireable(true),
+            )
+            .await
+            .unwrap();
+        assert_eq!(data.bio, Some("Rust and Python".to_owned()));
+        assert_eq!(data.hireable, Some(true));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "auth")]
+    async fn bad_token_gives_bad_credentials() {
+        let client = cassette_client("users/bad_credentials");
+        let users = Users::new(&client);
+        let err = users.user("mojombo").await.unwrap_err();
+        assert!(matches!(err, Error::BadCredentials(_)));
+    }
 }